@@ -0,0 +1,28 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rock_paper_scissors::{run_many, BestOf, FrequencyStrategy, RandomStrategy};
+use std::hint::black_box;
+
+const MATCHES: usize = 1_000;
+
+fn frequency_against_random(c: &mut Criterion) {
+    let mut group = c.benchmark_group("run_many");
+    group.throughput(Throughput::Elements(MATCHES as u64));
+    group.bench_function("frequency_vs_random", |b| {
+        let mut rng = StdRng::seed_from_u64(0);
+        b.iter(|| {
+            run_many(
+                black_box(MATCHES),
+                BestOf::default(),
+                FrequencyStrategy::default,
+                || RandomStrategy,
+                &mut rng,
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, frequency_against_random);
+criterion_main!(benches);
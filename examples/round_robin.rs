@@ -0,0 +1,33 @@
+use rock_paper_scissors::{round_robin, strategies, BestOf, StrategyConfig};
+use std::env;
+
+const MATCHES: usize = 1_000;
+
+/// Prints how often each built-in strategy, down the rows, wins against each
+/// other one, across the columns. Pass a seed to get a different but still
+/// reproducible grid.
+fn main() {
+    let seed = env::args()
+        .nth(1)
+        .map(|seed| seed.parse().expect("seed must be a number"))
+        .unwrap_or(0);
+    let configs: Vec<StrategyConfig> = strategies()
+        .iter()
+        .filter_map(|info| info.name.parse().ok())
+        .collect();
+    let grid = round_robin(&configs, BestOf::default(), MATCHES, seed);
+
+    print!("{:>14}", "");
+    for config in grid.strategies() {
+        print!("{:>14}", config.to_string());
+    }
+    println!();
+    for (first, config) in grid.strategies().iter().enumerate() {
+        print!("{:>14}", config.to_string());
+        for second in 0..grid.strategies().len() {
+            print!("{:>13.1}%", grid.win_percentage(first, second));
+        }
+        println!();
+    }
+    println!("{} matches per pairing, seed {}", grid.matches(), seed);
+}
@@ -0,0 +1,25 @@
+//! Feeds arbitrary input into every parser of the crate. They must only ever
+//! return `Ok` or `Err`, never panic.
+//!
+//! Run with a nightly toolchain and cargo-fuzz installed:
+//!
+//! ```text
+//! cargo install cargo-fuzz
+//! cargo +nightly fuzz run parsers
+//! ```
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rock_paper_scissors::{BestOf, Choice, Game, Winner};
+use std::convert::TryFrom;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    let _ = Choice::try_from(input.to_string());
+    let _ = input.parse::<Choice>();
+    let _ = input.parse::<BestOf>();
+    let _ = input.parse::<Winner>();
+    let _ = Game::load(data);
+});
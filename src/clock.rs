@@ -0,0 +1,68 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Source of the current time for time limits, so they can be tested without
+/// waiting.
+pub trait Clock: Debug {
+    fn now(&self) -> Instant;
+
+    /// Time passed since `earlier`, zero if `earlier` is in the future.
+    fn elapsed_since(&self, earlier: Instant) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+/// The wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock that only moves when [`MockClock::advance`] is called. Clones share
+/// the same time, so one clone can be handed to the code under test and
+/// another advanced from the outside.
+#[derive(Debug, Clone)]
+pub struct MockClock(Arc<Mutex<Instant>>);
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(Instant::now())))
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_clock_moves_only_when_advanced() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        assert_eq!(clock.elapsed_since(start), Duration::ZERO);
+
+        clock.clone().advance(Duration::from_secs(3));
+        assert_eq!(clock.elapsed_since(start), Duration::from_secs(3));
+        assert_eq!(MockClock::new().elapsed_since(clock.now()), Duration::ZERO);
+    }
+}
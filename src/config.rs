@@ -0,0 +1,113 @@
+use crate::{BestOf, RpsError};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Default options read from a config file, e.g.
+///
+/// ```text
+/// # ~/.config/rps/config.toml
+/// best_of = 7
+/// difficulty = "markov"
+/// color = false
+/// emoji = true
+/// ```
+///
+/// Only this flat `key = value` subset of TOML is understood. Every key is
+/// optional; missing keys leave the built-in default in place.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    pub best_of: Option<BestOf>,
+    pub difficulty: Option<String>,
+    pub color: Option<bool>,
+    pub emoji: Option<bool>,
+}
+
+impl Config {
+    /// `$XDG_CONFIG_HOME/rps/config.toml`, falling back to
+    /// `$HOME/.config/rps/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        let base = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+        Some(base.join("rps").join("config.toml"))
+    }
+
+    /// Reads the config at `path`. A missing file is the default config.
+    pub fn load(path: &Path) -> Result<Config, RpsError> {
+        match fs::read_to_string(path) {
+            Ok(text) => text.parse(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(RpsError::InvalidConfig(error.to_string())),
+        }
+    }
+}
+
+impl FromStr for Config {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| RpsError::InvalidConfig(reason);
+        let flag = |key: &str, value: &str| match value {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(invalid(format!(
+                "{} must be true or false, got {}",
+                key, value
+            ))),
+        };
+
+        let mut config = Config::default();
+        for line in s.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected key = value, got '{}'", line)))?;
+            let (key, value) = (key.trim(), value.trim().trim_matches('"'));
+            match key {
+                "best_of" => config.best_of = Some(value.parse()?),
+                "difficulty" => config.difficulty = Some(value.to_string()),
+                "color" => config.color = Some(flag(key, value)?),
+                "emoji" => config.emoji = Some(flag(key, value)?),
+                _ => return Err(invalid(format!("unknown key '{}'", key))),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_reads_known_keys() {
+        let text = "# defaults\nbest_of = 7\ndifficulty = \"markov\" # hard\n\ncolor = false\n";
+        let config: Config = text.parse().unwrap();
+        assert_eq!(
+            config,
+            Config {
+                best_of: Some(BestOf::new(7).unwrap()),
+                difficulty: Some("markov".to_string()),
+                color: Some(false),
+                emoji: None,
+            }
+        );
+        assert!(matches!(
+            "colour = false".parse::<Config>(),
+            Err(RpsError::InvalidConfig(_))
+        ));
+        assert!("emoji = yes".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn missing_config_file_is_default() {
+        let path = Path::new("tests/fixtures/no-such-config.toml");
+        assert_eq!(Config::load(path), Ok(Config::default()));
+    }
+}
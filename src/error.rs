@@ -0,0 +1,101 @@
+use crate::Choice;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt::Display;
+use core::num::ParseIntError;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpsError {
+    /// Best of value that is even or smaller than 3.
+    InvalidBestOf(u8),
+    /// Best of value above the allowed maximum.
+    BestOfTooLarge { value: u8, max: u8 },
+    /// Best of value that is not a number.
+    ParseError(ParseIntError),
+    /// Input that does not name any choice, with the closest choice when
+    /// the input looks like a typo of one.
+    UnknownChoice {
+        input: String,
+        suggestion: Option<Choice>,
+    },
+    /// Entry of a move list, at the zero based `index`, that is not a choice.
+    InvalidMove { index: usize, error: Box<RpsError> },
+    /// Input that does not name any winner.
+    UnknownWinner(String),
+    /// Saved game that could not be read.
+    InvalidSave(String),
+    /// Byte of [`Choice::pack`](crate::Choice::pack)ed choices that does not
+    /// hold valid choice codes.
+    InvalidPackedChoice(u8),
+    /// Tournament that can't be created or a match in it that can't be played.
+    InvalidTournament(String),
+    /// Round played after the game was already over.
+    GameOver,
+    /// Swap by a player who already swapped in this game.
+    SwapUsed,
+    /// Game configured so it is over before its first round.
+    EmptyGame,
+    /// Language code or name that is not supported.
+    UnknownLanguage(String),
+    /// Game log line that could not be read.
+    InvalidLog(String),
+    /// Strategy name that does not match any built-in strategy.
+    UnknownStrategy(String),
+    /// Config file that could not be read.
+    InvalidConfig(String),
+    /// Draw to be resolved by a coin flip in a round played without an RNG,
+    /// see [`Game::play_round_with_rng`](crate::Game::play_round_with_rng).
+    RngRequired,
+}
+
+impl Display for RpsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RpsError::InvalidBestOf(value) => {
+                write!(f, "Number must be odd and greater than 2, got {}", value)
+            }
+            RpsError::BestOfTooLarge { value, max } => {
+                write!(f, "Number must be at most {}, got {}", max, value)
+            }
+            RpsError::ParseError(_) => write!(f, "Could not parse number"),
+            RpsError::UnknownChoice {
+                input,
+                suggestion: None,
+            } => write!(f, "Unknown choice '{}'", input),
+            RpsError::UnknownChoice {
+                input,
+                suggestion: Some(choice),
+            } => write!(f, "Unknown choice '{}', did you mean {}?", input, choice),
+            RpsError::InvalidMove { index, error } => {
+                write!(f, "Move {} is invalid: {}", index + 1, error)
+            }
+            RpsError::UnknownWinner(input) => write!(f, "Unknown winner '{}'", input),
+            RpsError::InvalidSave(reason) => write!(f, "Invalid saved game: {}", reason),
+            RpsError::InvalidPackedChoice(byte) => {
+                write!(f, "Invalid packed choices in byte {:#010b}", byte)
+            }
+            RpsError::InvalidTournament(reason) => write!(f, "Invalid tournament: {}", reason),
+            RpsError::GameOver => write!(f, "The game is already over"),
+            RpsError::SwapUsed => write!(f, "The swap was already used in this game"),
+            RpsError::EmptyGame => write!(f, "The game would be over before its first round"),
+            RpsError::UnknownLanguage(name) => write!(f, "Unknown language '{}'", name),
+            RpsError::InvalidLog(reason) => write!(f, "Invalid game log: {}", reason),
+            RpsError::UnknownStrategy(name) => write!(f, "Unknown strategy '{}'", name),
+            RpsError::InvalidConfig(reason) => write!(f, "Invalid config: {}", reason),
+            RpsError::RngRequired => write!(f, "Resolving the draw at random needs an RNG"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for RpsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RpsError::ParseError(error) => Some(error),
+            RpsError::InvalidMove { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{Choice, RpsError};
+use alloc::string::ToString;
+use core::str::FromStr;
+
+/// Language whose words for the choices are accepted besides the English
+/// ones, which are always accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Language {
+    #[default]
+    English,
+    Polish,
+}
+
+const POLISH_WORDS: &[(&str, Choice)] = &[
+    ("kamień", Choice::Rock),
+    ("kamien", Choice::Rock),
+    ("papier", Choice::Paper),
+    ("nożyce", Choice::Scissors),
+    ("nozyce", Choice::Scissors),
+];
+
+impl Language {
+    /// Lowercase words for the choices in this language, other than the
+    /// English ones.
+    pub fn words(&self) -> &'static [(&'static str, Choice)] {
+        match self {
+            Language::English => &[],
+            Language::Polish => POLISH_WORDS,
+        }
+    }
+
+    /// Language of a locale such as the `LANG` environment variable, e.g.
+    /// `pl_PL.UTF-8`, falling back to English for unknown locales.
+    pub fn from_locale(locale: &str) -> Language {
+        let code = locale.split(['_', '.', '-']).next().unwrap_or_default();
+        code.parse().unwrap_or_default()
+    }
+}
+
+/// Parses a language code or name, e.g. `pl` or `polish`.
+impl FromStr for Language {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "en" | "english" => Ok(Language::English),
+            "pl" | "polish" | "polski" => Ok(Language::Polish),
+            _ => Err(RpsError::UnknownLanguage(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseOptions;
+
+    #[test]
+    fn polish_words_parse_with_polish_language() {
+        let options = ParseOptions::default().with_language(Language::Polish);
+        for (input, choice) in &[
+            ("kamień", Choice::Rock),
+            ("Papier", Choice::Paper),
+            ("NOŻYCE\n", Choice::Scissors),
+            ("nozyce", Choice::Scissors),
+            ("rock", Choice::Rock),
+        ] {
+            assert_eq!(Choice::parse_with_options(input, options), Ok(*choice));
+        }
+        assert!("kamień".parse::<Choice>().is_err());
+    }
+
+    #[test]
+    fn language_comes_from_code_or_locale() {
+        assert_eq!("pl".parse(), Ok(Language::Polish));
+        assert_eq!("English".parse(), Ok(Language::English));
+        assert!("xx".parse::<Language>().is_err());
+        assert_eq!(Language::from_locale("pl_PL.UTF-8"), Language::Polish);
+        assert_eq!(Language::from_locale("C.UTF-8"), Language::English);
+        assert_eq!(Language::from_locale(""), Language::English);
+    }
+}
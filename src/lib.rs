@@ -1,32 +1,172 @@
+//! Rock, paper, scissors game logic.
+//!
+//! The `std` feature, on by default, adds strategies, statistics, rendering,
+//! saving and simulations. Without it the crate is `no_std` and only needs
+//! `alloc`: choices, outcomes, scoring and the rules for when a game ends
+//! still work. Check that it builds with `cargo build --no-default-features`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display};
+use core::iter::FromIterator;
+use core::str::FromStr;
 use rand::distributions::Standard;
 use rand::prelude::Distribution;
-use std::cmp::Ordering;
-use std::convert::TryFrom;
-use std::fmt::{Debug, Display};
-use std::str::FromStr;
+use rand::{Rng, RngCore};
+
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "std")]
+mod config;
+mod error;
+mod language;
+#[cfg(feature = "std")]
+mod lifetime;
+mod phase;
+#[cfg(feature = "std")]
+mod recording;
+#[cfg(feature = "std")]
+pub mod render;
+mod rules;
+#[cfg(feature = "std")]
+mod save;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+mod simulation;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod strategy;
+#[cfg(feature = "std")]
+mod stream;
+mod swap;
+mod tournament;
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "std")]
+pub use clock::{Clock, MockClock, SystemClock};
+#[cfg(feature = "std")]
+pub use config::Config;
+pub use error::RpsError;
+pub use language::Language;
+#[cfg(feature = "std")]
+pub use lifetime::{LifetimeStats, LoggedGame};
+#[cfg(feature = "std")]
+pub use phase::CommittedChoice;
+pub use phase::GamePhase;
+#[cfg(feature = "std")]
+pub use recording::GameRecording;
+pub use rules::{AlternatingRules, Rule, RuleSchedule};
+#[cfg(feature = "std")]
+pub use shared::SharedGame;
+#[cfg(feature = "std")]
+pub use simulation::{round_robin, run_many, Grid, TournamentResult};
+#[cfg(feature = "std")]
+pub use stats::{ChoiceBreakdown, GameStats};
+#[cfg(feature = "std")]
+pub use strategy::{
+    strategies, strategy_by_name, AdaptiveStrategy, AlwaysRockStrategy, FrequencyStrategy,
+    LuckyStrategy, MarkovStrategy, MirrorStrategy, RandomStrategy, ShuffleBagStrategy, Strategy,
+    StrategyConfig, StrategyInfo, WeightedStrategy,
+};
+#[cfg(feature = "std")]
+pub use stream::PlayStream;
+pub use swap::Swap;
+pub use tournament::{BracketMatch, Tournament};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Winner {
     Human,
     Computer,
     Draw,
 }
 
+/// Result of one choice played against another, from the point of view of the
+/// first one, see [`Choice::play_against`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayResult {
+    Win,
+    Lose,
+    Tie,
+}
+
+/// `{}` prints the plain name, `{:#}` decorates it with a trophy or a handshake.
 impl Display for Winner {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Winner::Human => "Human",
-                Winner::Computer => "Computer",
-                Winner::Draw => "Draw",
-            }
-        )
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Winner::Human => "Human",
+            Winner::Computer => "Computer",
+            Winner::Draw => "Draw",
+        };
+        match (f.alternate(), self) {
+            (false, _) => write!(f, "{}", name),
+            (true, Winner::Draw) => write!(f, "🤝 {}", name),
+            (true, _) => write!(f, "🏆 {}", name),
+        }
+    }
+}
+
+impl Winner {
+    /// Emoji standing for the winner: 🧑 for the human, 🤖 for the computer
+    /// and 🤝 for a draw.
+    pub fn as_emoji(&self) -> &'static str {
+        match self {
+            Winner::Human => "🧑",
+            Winner::Computer => "🤖",
+            Winner::Draw => "🤝",
+        }
+    }
+
+    /// Displays the winner with `computer_name` in place of "Computer".
+    pub fn named<'a>(&'a self, computer_name: &'a str) -> NamedWinner<'a> {
+        NamedWinner {
+            winner: self,
+            computer_name,
+        }
+    }
+}
+
+/// [`Winner`] formatter that shows a custom name for the computer.
+#[derive(Debug, Clone, Copy)]
+pub struct NamedWinner<'a> {
+    winner: &'a Winner,
+    computer_name: &'a str,
+}
+
+impl Display for NamedWinner<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.winner {
+            Winner::Computer if f.alternate() => write!(f, "🏆 {}", self.computer_name),
+            Winner::Computer => write!(f, "{}", self.computer_name),
+            winner if f.alternate() => write!(f, "{:#}", winner),
+            winner => write!(f, "{}", winner),
+        }
+    }
+}
+
+impl FromStr for Winner {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "human" => Ok(Winner::Human),
+            "computer" => Ok(Winner::Computer),
+            "draw" => Ok(Winner::Draw),
+            _ => Err(RpsError::UnknownWinner(s.trim().to_string())),
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Round {
     number: u8,
     round_winner: Option<Winner>,
@@ -44,299 +184,2664 @@ impl Round {
         self.round_winner = Some(winner);
     }
 
+    /// Moves on to the next round, staying at `u8::MAX` at the end.
     pub fn increase(&mut self) {
-        self.number += 1
+        self.number = self.number.saturating_add(1)
+    }
+}
+
+impl Default for Round {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl Display for Round {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.number)
     }
 }
 
-#[derive(Debug)]
-pub struct BestOf(u8);
+/// Choices made in a single round and who won it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoundOutcome {
+    human: Choice,
+    computer: Choice,
+    winner: Winner,
+}
+
+impl RoundOutcome {
+    pub fn new(human: Choice, computer: Choice, winner: Winner) -> Self {
+        Self {
+            human,
+            computer,
+            winner,
+        }
+    }
+
+    pub fn human(&self) -> Choice {
+        self.human
+    }
+
+    pub fn computer(&self) -> Choice {
+        self.computer
+    }
+
+    pub fn winner(&self) -> Winner {
+        self.winner
+    }
+
+    /// Choice of the player who lost the round, `None` on a draw.
+    pub fn loser_choice(&self) -> Option<Choice> {
+        match self.winner {
+            Winner::Human => Some(self.computer),
+            Winner::Computer => Some(self.human),
+            Winner::Draw => None,
+        }
+    }
+
+    /// The same outcome seen from the computer's side, with the players swapped.
+    pub fn mirrored(&self) -> RoundOutcome {
+        RoundOutcome {
+            human: self.computer,
+            computer: self.human,
+            winner: match self.winner {
+                Winner::Human => Winner::Computer,
+                Winner::Computer => Winner::Human,
+                Winner::Draw => Winner::Draw,
+            },
+        }
+    }
+}
+
+/// Final score of a game, returned by [`Game::result`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameResult {
+    pub winner: Winner,
+    pub human_points: u8,
+    pub computer_points: u8,
+    pub draws: u8,
+    pub rounds_played: u8,
+}
+
+/// Running count of round winners, e.g.
+/// `history.iter().map(|record| record.outcome().winner()).collect::<ScoreTally>()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScoreTally {
+    pub human: u32,
+    pub computer: u32,
+    pub draws: u32,
+}
+
+impl ScoreTally {
+    /// Number of winners counted, draws included.
+    pub fn total(&self) -> u32 {
+        self.human + self.computer + self.draws
+    }
+}
+
+impl Extend<Winner> for ScoreTally {
+    fn extend<I: IntoIterator<Item = Winner>>(&mut self, winners: I) {
+        for winner in winners {
+            match winner {
+                Winner::Human => self.human += 1,
+                Winner::Computer => self.computer += 1,
+                Winner::Draw => self.draws += 1,
+            }
+        }
+    }
+}
+
+impl FromIterator<Winner> for ScoreTally {
+    fn from_iter<I: IntoIterator<Item = Winner>>(winners: I) -> Self {
+        let mut tally = ScoreTally::default();
+        tally.extend(winners);
+        tally
+    }
+}
+
+/// A [`RoundOutcome`] stored in the game history together with its round number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoundRecord {
+    round: u8,
+    outcome: RoundOutcome,
+}
+
+impl RoundRecord {
+    pub fn round(&self) -> u8 {
+        self.round
+    }
+
+    pub fn outcome(&self) -> &RoundOutcome {
+        &self.outcome
+    }
+
+    /// The human's and the computer's choice, e.g. for counting how often
+    /// each pairing came up regardless of round number.
+    pub fn pairing_key(&self) -> (Choice, Choice) {
+        (self.outcome.human, self.outcome.computer)
+    }
+
+    /// One sentence narrating the round, e.g. `Round 2: you played Paper, the
+    /// computer played Rock — you win (paper covers rock).` The reason is left
+    /// out when the winning choice doesn't beat the other one under the normal
+    /// rules, e.g. in a chaos round.
+    pub fn describe(&self) -> String {
+        let outcome = &self.outcome;
+        let result = match outcome.winner {
+            Winner::Human => "you win",
+            Winner::Computer => "the computer wins",
+            Winner::Draw => "it's a draw",
+        };
+        let reason = match outcome.winner {
+            Winner::Human => win_reason(outcome.human, outcome.computer),
+            Winner::Computer => win_reason(outcome.computer, outcome.human),
+            Winner::Draw => None,
+        };
+        let mut sentence = format!(
+            "Round {}: you played {}, the computer played {} — {}",
+            self.round, outcome.human, outcome.computer, result
+        );
+        if let Some(reason) = reason {
+            sentence.push_str(&format!(" ({})", reason));
+        }
+        sentence.push('.');
+        sentence
+    }
+}
+
+/// Why `winner` beats `loser` under the normal rules, if it does, e.g.
+/// `paper covers rock`.
+fn win_reason(winner: Choice, loser: Choice) -> Option<String> {
+    RULES
+        .iter()
+        .find(|entry| entry.winner == winner && entry.loser == loser)
+        .map(|entry| format!("{} {} {}", winner, entry.verb, loser).to_lowercase())
+}
+
+/// One winning relationship of the normal rules, e.g. Paper covers Rock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RuleEntry {
+    pub winner: Choice,
+    pub loser: Choice,
+    pub verb: &'static str,
+}
+
+/// Every winning relationship of the normal rules, for rule references and
+/// round descriptions.
+pub const RULES: &[RuleEntry] = &[
+    RuleEntry {
+        winner: Choice::Rock,
+        loser: Choice::Scissors,
+        verb: "crushes",
+    },
+    RuleEntry {
+        winner: Choice::Paper,
+        loser: Choice::Rock,
+        verb: "covers",
+    },
+    RuleEntry {
+        winner: Choice::Scissors,
+        loser: Choice::Paper,
+        verb: "cut",
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BestOf(u8, Option<DrawResolution>);
 
 impl BestOf {
-    pub fn new(number: u8) -> Result<Self, &'static str> {
-        if (number % 2 != 0) && (number > 2) {
-            Ok(Self(number))
+    /// Largest number of rounds accepted by [`BestOf::new`].
+    pub const DEFAULT_MAX: u8 = 99;
+
+    pub fn new(number: u8) -> Result<Self, RpsError> {
+        BestOf::with_max(number, BestOf::DEFAULT_MAX)
+    }
+
+    /// Like [`BestOf::new`], with a custom largest number of rounds.
+    pub fn with_max(number: u8, max: u8) -> Result<Self, RpsError> {
+        if number > max {
+            Err(RpsError::BestOfTooLarge { value: number, max })
+        } else if (number % 2 == 1) && (number > 2) {
+            Ok(Self(number, None))
+        } else {
+            Err(RpsError::InvalidBestOf(number))
+        }
+    }
+
+    /// Skips the parity and size checks, for deliberately long or unusual
+    /// games. Only a best of 0, which would be over before it started, fails
+    /// with [`RpsError::EmptyGame`].
+    pub fn new_unchecked(number: u8) -> Result<Self, RpsError> {
+        if number == 0 {
+            Err(RpsError::EmptyGame)
         } else {
-            Err("Number must be odd and greater than 2")
+            Ok(Self(number, None))
+        }
+    }
+
+    /// Like [`BestOf::new`], but an even number of at least 2 is also accepted
+    /// when a tie-break is configured, since rounds can then never be drawn.
+    /// The player who first reaches `number / 2 + 1` points still wins, so best
+    /// of 4 needs 3 points.
+    ///
+    /// A [`Game`] created with this best of uses the tie-break as its
+    /// [draw resolution](Game::with_draw_resolution), and when the players are
+    /// level after all rounds, e.g. 2-2 in a best of 4, the game goes on until
+    /// the next round decides it.
+    pub fn new_even_allowed(
+        number: u8,
+        tie_break: Option<DrawResolution>,
+    ) -> Result<Self, RpsError> {
+        match tie_break {
+            Some(_) if number > BestOf::DEFAULT_MAX => Err(RpsError::BestOfTooLarge {
+                value: number,
+                max: BestOf::DEFAULT_MAX,
+            }),
+            Some(_) if number >= 2 => Ok(Self(number, tie_break)),
+            _ => BestOf::new(number).map(|best_of| Self(best_of.0, tie_break)),
         }
     }
+
+    /// Tie-break given to [`BestOf::new_even_allowed`].
+    pub fn tie_break(&self) -> Option<DrawResolution> {
+        self.1
+    }
 }
 
 impl Default for BestOf {
     fn default() -> Self {
-        Self(5)
+        Self(5, None)
     }
 }
 
+/// Accepts a plain number of rounds, or "first to N" written as `ftN` or
+/// `first-to-N`, which is best of `2N - 1`.
 impl FromStr for BestOf {
-    type Err = &'static str;
+    type Err = RpsError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let first_to = s.strip_prefix("first-to-").or_else(|| s.strip_prefix("ft"));
+        if let Some(points) = first_to {
+            return match points.parse::<u8>() {
+                Ok(0) => Err(RpsError::InvalidBestOf(0)),
+                Ok(points) => {
+                    BestOf::new(u8::try_from(2 * u16::from(points) - 1).unwrap_or(u8::MAX))
+                }
+                Err(error) => Err(RpsError::ParseError(error)),
+            };
+        }
         match s.parse::<u8>() {
             Ok(value) => BestOf::new(value),
-            Err(_) => Err("Could not parse number"),
+            Err(error) => Err(RpsError::ParseError(error)),
+        }
+    }
+}
+
+/// How a round that would end in a draw is resolved when draws are disallowed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawResolution {
+    /// The round is thrown away and played again.
+    Replay,
+    /// A coin flip decides the round. Without the `std` feature the coin
+    /// has to come from [`Game::play_round_with_rng`].
+    Random,
+    /// The human player wins the round.
+    FavorPlayer,
+}
+
+/// When a game ends.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum WinCondition {
+    /// First to win the majority of the best of rounds, or after all of them.
+    #[default]
+    BestOf,
+    /// First to win the given number of rounds, however many rounds it takes.
+    FirstTo(u8),
+    /// Never ends on its own, rounds are played until the caller stops.
+    Endless,
+    /// A best of game that keeps close matches going. Whenever it would end,
+    /// because someone has the majority of its rounds or all of them were
+    /// played, with the players at most `margin` points apart, it becomes a
+    /// best of two rounds more instead, as long as that is at most
+    /// `max_length` rounds. Wider margins end the game as usual.
+    AdaptiveLength { margin: u8, max_length: u8 },
+}
+
+/// How rounds turn into each player's score.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Scoring {
+    /// Only won rounds score a point.
+    #[default]
+    WinsOnly,
+    /// Won rounds score a point and every draw scores half a point for both
+    /// players. Equal scores are broken by the most recent decisive round.
+    HalfPointDraws,
+    /// Every win in a streak of consecutive wins is worth one point more than
+    /// the one before it: 1, then 2, then 3, and so on. A loss or a draw ends
+    /// the streak. Scores are computed from the history, where won rounds
+    /// without one count a point each, and the game ends once a score reaches
+    /// the points needed to win.
+    StreakBonus,
+}
+
+/// One of the two players, for scores, which a draw has none of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Player {
+    Human,
+    Computer,
+}
+
+impl Player {
+    fn winner(self) -> Winner {
+        match self {
+            Player::Human => Winner::Human,
+            Player::Computer => Winner::Computer,
         }
     }
 }
 
-#[derive(Debug)]
+/// Clones share the [rule schedule](Game::with_rule_schedule), which is never
+/// changed once set.
+#[derive(Debug, Clone)]
 pub struct Game {
     human_points: u8,
     computer_points: u8,
+    draws: u8,
     round: Round,
     best_of: BestOf,
+    history: Vec<RoundRecord>,
+    draw_resolution: Option<DrawResolution>,
+    rule_schedule: Option<Arc<dyn RuleSchedule>>,
+    win_condition: WinCondition,
+    scoring: Scoring,
+    stop_early: bool,
+    max_rounds: Option<u8>,
+    human_swapped: bool,
+    computer_swapped: bool,
+    adaptive_length: Option<u8>,
+}
+
+/// A fresh best of 5 game.
+impl Default for Game {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+/// Games are equal when their scores, round number, draws and best of match.
+/// History and draw resolution are not compared.
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        self.human_points == other.human_points
+            && self.computer_points == other.computer_points
+            && self.draws == other.draws
+            && self.round.number == other.round.number
+            && self.best_of == other.best_of
+    }
+}
+
+/// Lightweight snapshot of the scores, round number, draws and spent
+/// [swaps](Game::play_round_with_swap) of a [`Game`].
+///
+/// Only the length of the history is captured, not its rounds, which keeps
+/// checkpoints cheap for lookahead search. Restoring drops the rounds recorded
+/// after the checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checkpoint {
+    human_points: u8,
+    computer_points: u8,
+    draws: u8,
+    round: u8,
+    history_len: usize,
+    human_swapped: bool,
+    computer_swapped: bool,
+    adaptive_length: Option<u8>,
+}
+
+impl Checkpoint {
+    pub fn human_points(&self) -> u8 {
+        self.human_points
+    }
+
+    pub fn computer_points(&self) -> u8 {
+        self.computer_points
+    }
+
+    pub fn draws(&self) -> u8 {
+        self.draws
+    }
+
+    pub fn round(&self) -> u8 {
+        self.round
+    }
 }
 
 impl Game {
     pub fn new(best_of: Option<BestOf>) -> Self {
+        let best_of = best_of.unwrap_or_default();
         Self {
             human_points: 0,
             computer_points: 0,
             round: Round::new(),
-            best_of: match best_of {
-                Some(value) => value,
-                None => BestOf::default(),
-            },
+            draws: 0,
+            best_of,
+            history: Vec::new(),
+            draw_resolution: best_of.tie_break(),
+            rule_schedule: None,
+            win_condition: WinCondition::default(),
+            scoring: Scoring::default(),
+            stop_early: true,
+            max_rounds: None,
+            human_swapped: false,
+            computer_swapped: false,
+            adaptive_length: None,
         }
     }
 
-    pub fn add_point(&mut self, player: &Winner) {
-        match player {
-            Winner::Human => self.human_points += 1,
-            Winner::Computer => self.computer_points += 1,
-            Winner::Draw => (),
+    /// Creates a game in an arbitrary state with an empty history.
+    pub fn with_state(
+        best_of: BestOf,
+        human_points: u8,
+        computer_points: u8,
+        draws: u8,
+        round: u8,
+    ) -> Self {
+        Self {
+            human_points,
+            computer_points,
+            draws,
+            round: Round {
+                number: round,
+                round_winner: None,
+            },
+            ..Self::new(Some(best_of))
         }
     }
 
-    pub fn round(&mut self) -> &mut Round {
-        &mut self.round
+    /// Creates a game from a plain number of rounds, validated like [`BestOf::new`].
+    pub fn best_of_rounds(rounds: u8) -> Result<Self, RpsError> {
+        Ok(Self::new(Some(BestOf::new(rounds)?)))
     }
 
-    pub fn human_points(&self) -> u8 {
-        self.human_points
+    /// Starts the game over with the same settings, keeping allocated history.
+    pub fn reset(&mut self) {
+        self.human_points = 0;
+        self.computer_points = 0;
+        self.draws = 0;
+        self.round = Round::new();
+        self.history.clear();
+        self.human_swapped = false;
+        self.computer_swapped = false;
+        self.adaptive_length = None;
     }
 
-    pub fn computer_points(&self) -> u8 {
-        self.computer_points
+    /// Awards a point without any checks, see [`Game::play_round`] for
+    /// playing a round safely.
+    pub fn add_point(&mut self, player: &Winner) {
+        let points = match player {
+            Winner::Human => &mut self.human_points,
+            Winner::Computer => &mut self.computer_points,
+            Winner::Draw => &mut self.draws,
+        };
+        *points = points.saturating_add(1);
     }
 
-    pub fn best_of(&self) -> u8 {
-        self.best_of.0
+    /// Disallows draws, resolving them with `resolution` instead.
+    pub fn with_draw_resolution(mut self, resolution: DrawResolution) -> Self {
+        self.draw_resolution = Some(resolution);
+        self
     }
 
-    pub fn round_winner(&self, human_choice: &Choice, computer_choice: &Choice) -> Winner {
-        let result = human_choice.partial_cmp(computer_choice).unwrap();
-        match result {
-            Ordering::Greater => Winner::Human,
-            Ordering::Less => Winner::Computer,
-            Ordering::Equal => Winner::Draw,
-        }
+    pub fn draw_resolution(&self) -> Option<DrawResolution> {
+        self.draw_resolution
     }
 
-    pub fn game_winner(&self) -> Winner {
-        if self.computer_points > self.human_points {
-            Winner::Computer
-        } else if self.computer_points < self.human_points {
-            Winner::Human
-        } else {
-            Winner::Draw
-        }
+    pub fn with_win_condition(mut self, win_condition: WinCondition) -> Self {
+        self.win_condition = win_condition;
+        self
     }
 
-    pub fn enough_points_to_end_game(&self) -> bool {
-        let minimum_round = (self.best_of() / 2) + 1;
-        if (self.human_points == minimum_round) | (self.computer_points == minimum_round) {
-            return true;
+    /// Like [`Game::with_win_condition`] for conditions from untrusted input.
+    /// Fails with [`RpsError::EmptyGame`] for [`WinCondition::FirstTo`] 0,
+    /// which would be over before its first round.
+    pub fn try_with_win_condition(self, win_condition: WinCondition) -> Result<Self, RpsError> {
+        if win_condition == WinCondition::FirstTo(0) {
+            return Err(RpsError::EmptyGame);
         }
-        false
+        Ok(self.with_win_condition(win_condition))
     }
-}
-
-#[derive(Debug, PartialEq)]
-pub enum Choice {
-    Rock,
-    Paper,
-    Scissors,
-}
 
-impl Distribution<Choice> for Standard {
-    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Choice {
-        match rng.gen_range(0..=2) {
-            0 => Choice::Rock,
-            1 => Choice::Paper,
-            _ => Choice::Scissors,
-        }
+    pub fn win_condition(&self) -> WinCondition {
+        self.win_condition
     }
-}
 
-impl TryFrom<String> for Choice {
-    type Error = &'static str;
+    /// With `false`, a [`WinCondition::BestOf`] game goes on after someone
+    /// clinched it until all best of rounds are played. The winner is still
+    /// whoever has the higher score at the end.
+    pub fn with_stop_early(mut self, stop_early: bool) -> Self {
+        self.stop_early = stop_early;
+        self
+    }
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        match value.to_lowercase().as_str() {
-            "rock\n" | "r\n" => Ok(Self::Rock),
-            "paper\n" | "p\n" => Ok(Self::Paper),
-            "scissors\n" | "s\n" => Ok(Self::Scissors),
-            _ => Err("Unknown choice"),
-        }
+    pub fn stop_early(&self) -> bool {
+        self.stop_early
     }
-}
 
-impl Display for Choice {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Choice::Paper => "Paper",
-                Choice::Rock => "Rock",
-                Choice::Scissors => "Scissors",
-            }
-        )
+    /// Ends the game after `max_rounds` rounds whatever the win condition,
+    /// e.g. as a safety cap for endless games. The winner is then whoever has
+    /// more points, see [`Game::game_winner`].
+    pub fn with_max_rounds(mut self, max_rounds: u8) -> Self {
+        self.max_rounds = Some(max_rounds);
+        self
     }
-}
 
-impl PartialOrd for Choice {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Self::Rock, &Choice::Paper) => Some(Ordering::Less),
-            (Self::Rock, &Choice::Scissors) => Some(Ordering::Greater),
-            (Self::Paper, &Choice::Rock) => Some(Ordering::Greater),
-            (Self::Paper, &Choice::Scissors) => Some(Ordering::Less),
-            (Self::Scissors, &Choice::Paper) => Some(Ordering::Greater),
-            (Self::Scissors, &Choice::Rock) => Some(Ordering::Less),
-            _ => Some(Ordering::Equal),
+    /// Like [`Game::with_max_rounds`] for a cap from untrusted input. Fails
+    /// with [`RpsError::EmptyGame`] for 0 rounds.
+    pub fn try_with_max_rounds(self, max_rounds: u8) -> Result<Self, RpsError> {
+        if max_rounds == 0 {
+            return Err(RpsError::EmptyGame);
         }
+        Ok(self.with_max_rounds(max_rounds))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    pub fn max_rounds(&self) -> Option<u8> {
+        self.max_rounds
+    }
 
-    #[test]
-    fn rock_beats_scissors() {
-        let rock = Choice::Rock;
-        let scissors = Choice::Scissors;
-        assert!(rock > scissors)
+    pub fn with_scoring(mut self, scoring: Scoring) -> Self {
+        self.scoring = scoring;
+        self
     }
 
-    #[test]
-    fn paper_beats_rock() {
-        let rock = Choice::Rock;
-        let paper = Choice::Paper;
-        assert!(paper > rock)
+    pub fn scoring(&self) -> Scoring {
+        self.scoring
     }
 
-    #[test]
-    fn scissors_beats_paper() {
-        let scissors = Choice::Scissors;
-        let paper = Choice::Paper;
-        assert!(scissors > paper)
+    /// Human score under the game's [`Scoring`].
+    pub fn human_score(&self) -> f32 {
+        f32::from(self.half_points(Player::Human)) / 2.0
     }
 
-    #[test]
-    fn rock_loses_to_paper() {
-        let rock = Choice::Rock;
-        let paper = Choice::Paper;
-        assert!(rock < paper)
+    /// Computer score under the game's [`Scoring`].
+    pub fn computer_score(&self) -> f32 {
+        f32::from(self.half_points(Player::Computer)) / 2.0
     }
 
-    #[test]
-    fn paper_loses_to_scissors() {
-        let scissors = Choice::Scissors;
-        let paper = Choice::Paper;
-        assert!(paper < scissors)
+    /// Rounds won by `player`.
+    fn points(&self, player: Player) -> u8 {
+        match player {
+            Player::Human => self.human_points,
+            Player::Computer => self.computer_points,
+        }
     }
 
-    #[test]
-    fn scissors_loses_to_rock() {
-        let scissors = Choice::Scissors;
-        let rock = Choice::Rock;
-        assert!(scissors < rock)
+    /// Score of `player` in half points, so draws worth half a point stay
+    /// exact.
+    fn half_points(&self, player: Player) -> u16 {
+        let points = self.points(player);
+        match self.scoring {
+            Scoring::WinsOnly => 2 * u16::from(points),
+            Scoring::HalfPointDraws => 2 * u16::from(points) + u16::from(self.draws),
+            Scoring::StreakBonus => 2 * self.streak_points(player),
+        }
     }
 
-    #[test]
-    fn same_choice_is_equal() {
-        let scissors = Choice::Scissors;
-        let paper = Choice::Paper;
-        let rock = Choice::Rock;
+    /// Points of `player` under [`Scoring::StreakBonus`]. Won rounds missing
+    /// from the history, e.g. those of [`Game::with_state`], count a point
+    /// each, since their streaks are unknown.
+    fn streak_points(&self, player: Player) -> u16 {
+        let mut streak = 0;
+        let mut points = 0u16;
+        let mut wins = 0u16;
+        for record in &self.history {
+            if record.outcome().winner() == player.winner() {
+                streak += 1;
+                wins += 1;
+                points = points.saturating_add(streak);
+            } else {
+                streak = 0;
+            }
+        }
+        points.saturating_add(u16::from(self.points(player)).saturating_sub(wins))
+    }
 
-        assert!(scissors == scissors);
-        assert!(paper == paper);
-        assert!(rock == rock);
+    /// Lets `schedule` decide the rule used to compare choices in each round.
+    pub fn with_rule_schedule(mut self, schedule: impl RuleSchedule + 'static) -> Self {
+        self.rule_schedule = Some(Arc::new(schedule));
+        self
     }
 
-    #[test]
-    fn human_gets_point() {
-        let mut game = Game::new(None);
-        game.add_point(&Winner::Human);
-        assert_eq!(game.human_points(), 1);
+    /// Rule used to decide the current round.
+    pub fn active_rule(&self) -> Rule {
+        match &self.rule_schedule {
+            Some(schedule) => schedule.rule(self.round.number),
+            None => Rule::Normal,
+        }
     }
 
-    #[test]
-    fn computer_gets_point() {
-        let mut game = Game::new(None);
-        game.add_point(&Winner::Computer);
-        assert_eq!(game.computer_points(), 1);
+    /// Decides the round, awards the point, records it in the history and
+    /// moves on to the next round.
+    ///
+    /// With [`DrawResolution::Replay`] a drawn round is returned as a draw but
+    /// not recorded, so the same round has to be played again.
+    /// [`DrawResolution::Random`] flips the coin with the thread RNG, see
+    /// [`Game::play_round_with_rng`] for a seeded one. Without the `std`
+    /// feature there is no thread RNG, so such a draw fails with
+    /// [`RpsError::RngRequired`].
+    ///
+    /// Fails with [`RpsError::GameOver`] once the game [is over](Game::is_over).
+    pub fn play_round(
+        &mut self,
+        human: Choice,
+        computer: Choice,
+    ) -> Result<RoundOutcome, RpsError> {
+        #[cfg(feature = "std")]
+        return self.play_round_with_rng(human, computer, &mut rand::thread_rng());
+        #[cfg(not(feature = "std"))]
+        return self.resolve_round(human, computer, None);
     }
 
-    #[test]
-    fn should_chose_round_winner() {
-        let game = Game::new(None);
-        let human_choice = Choice::Paper;
-        let computer_choice = Choice::Rock;
+    /// Like [`Game::play_round`], with [`DrawResolution::Random`] flipping
+    /// its coin with `rng`, so seeded games are reproducible.
+    pub fn play_round_with_rng(
+        &mut self,
+        human: Choice,
+        computer: Choice,
+        rng: &mut dyn RngCore,
+    ) -> Result<RoundOutcome, RpsError> {
+        self.resolve_round(human, computer, Some(rng))
+    }
 
+    fn resolve_round(
+        &mut self,
+        human: Choice,
+        computer: Choice,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<RoundOutcome, RpsError> {
+        if self.is_over() {
+            return Err(RpsError::GameOver);
+        }
+        let winner = match (self.round_winner(&human, &computer), self.draw_resolution) {
+            (Winner::Draw, Some(DrawResolution::Replay)) => {
+                return Ok(RoundOutcome {
+                    human,
+                    computer,
+                    winner: Winner::Draw,
+                });
+            }
+            (Winner::Draw, Some(DrawResolution::Random)) => {
+                if rng.ok_or(RpsError::RngRequired)?.gen() {
+                    Winner::Human
+                } else {
+                    Winner::Computer
+                }
+            }
+            (Winner::Draw, Some(DrawResolution::FavorPlayer)) => Winner::Human,
+            (winner, _) => winner,
+        };
+        let outcome = RoundOutcome {
+            human,
+            computer,
+            winner,
+        };
+        self.apply_outcome(outcome);
+        Ok(outcome)
+    }
+
+    /// Records a round decided elsewhere, e.g. by a referee: awards the point,
+    /// pushes it to the history and moves on to the next round. The choices
+    /// are not compared again.
+    pub fn apply_outcome(&mut self, outcome: RoundOutcome) {
+        self.add_point(&outcome.winner);
+        self.history.push(RoundRecord {
+            round: self.round.number,
+            outcome,
+        });
+        self.round.increase();
+        self.extend_adaptive_length();
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            human_points: self.human_points,
+            computer_points: self.computer_points,
+            draws: self.draws,
+            round: self.round.number,
+            history_len: self.history.len(),
+            human_swapped: self.human_swapped,
+            computer_swapped: self.computer_swapped,
+            adaptive_length: self.adaptive_length,
+        }
+    }
+
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.human_points = checkpoint.human_points;
+        self.computer_points = checkpoint.computer_points;
+        self.draws = checkpoint.draws;
+        self.round = Round {
+            number: checkpoint.round,
+            round_winner: None,
+        };
+        self.history.truncate(checkpoint.history_len);
+        self.human_swapped = checkpoint.human_swapped;
+        self.computer_swapped = checkpoint.computer_swapped;
+        self.adaptive_length = checkpoint.adaptive_length;
+    }
+
+    pub fn round(&mut self) -> &mut Round {
+        &mut self.round
+    }
+
+    /// Number of the round about to be played, starting at 1.
+    pub fn round_number(&self) -> u8 {
+        self.round.number
+    }
+
+    /// Number of rounds completed so far, 0 before the first round. Counted
+    /// from the round number, so games made with [`Game::with_state`] count
+    /// rounds they have no history for.
+    pub fn rounds_played(&self) -> u8 {
+        self.round.number.saturating_sub(1)
+    }
+
+    /// Rounds left before the best of limit, `None` for endless games.
+    pub fn rounds_remaining(&self) -> Option<u8> {
+        match self.win_condition {
+            WinCondition::BestOf => Some(self.best_of().saturating_sub(self.rounds_played())),
+            WinCondition::AdaptiveLength { .. } => {
+                Some(self.adaptive_length().saturating_sub(self.rounds_played()))
+            }
+            WinCondition::FirstTo(_) | WinCondition::Endless => None,
+        }
+    }
+
+    pub fn human_points(&self) -> u8 {
+        self.human_points
+    }
+
+    pub fn computer_points(&self) -> u8 {
+        self.computer_points
+    }
+
+    pub fn draws(&self) -> u8 {
+        self.draws
+    }
+
+    pub fn best_of(&self) -> u8 {
+        self.best_of.0
+    }
+
+    pub fn history(&self) -> &[RoundRecord] {
+        &self.history
+    }
+
+    #[cfg(feature = "std")]
+    pub fn stats(&self) -> GameStats {
+        GameStats::from_history(&self.history)
+    }
+
+    pub fn round_winner(&self, human_choice: &Choice, computer_choice: &Choice) -> Winner {
+        // Inverting the rules is the same as the players swapping choices.
+        let human_result = match self.active_rule() {
+            Rule::Normal => human_choice.play_against(computer_choice),
+            Rule::Inverted => computer_choice.play_against(human_choice),
+        };
+        match human_result {
+            PlayResult::Win => Winner::Human,
+            PlayResult::Lose => Winner::Computer,
+            PlayResult::Tie => Winner::Draw,
+        }
+    }
+
+    /// Player with the higher score. With [`Scoring::WinsOnly`] and
+    /// [`Scoring::StreakBonus`] equal scores are a draw; with [`Scoring::HalfPointDraws`] they go to whoever won
+    /// the most recent decisive round, and are a draw only if every round
+    /// was drawn.
+    pub fn game_winner(&self) -> Winner {
+        let human = self.half_points(Player::Human);
+        let computer = self.half_points(Player::Computer);
+        match (human.cmp(&computer), self.scoring) {
+            (Ordering::Greater, _) => Winner::Human,
+            (Ordering::Less, _) => Winner::Computer,
+            (Ordering::Equal, Scoring::WinsOnly | Scoring::StreakBonus) => Winner::Draw,
+            (Ordering::Equal, Scoring::HalfPointDraws) => self
+                .history
+                .iter()
+                .rev()
+                .map(|record| record.outcome().winner())
+                .find(|winner| *winner != Winner::Draw)
+                .unwrap_or(Winner::Draw),
+        }
+    }
+
+    /// Who won more of the last `window` rounds, regardless of the total score.
+    pub fn momentum(&self, window: usize) -> Winner {
+        let start = self.history.len().saturating_sub(window);
+        let recent = &self.history[start..];
+        let wins = |winner: Winner| {
+            recent
+                .iter()
+                .filter(|record| record.outcome.winner == winner)
+                .count()
+        };
+        let (human, computer) = (wins(Winner::Human), wins(Winner::Computer));
+        match human.cmp(&computer) {
+            Ordering::Greater => Winner::Human,
+            Ordering::Less => Winner::Computer,
+            Ordering::Equal => Winner::Draw,
+        }
+    }
+
+    /// Single-line `key=value` summary of the game, stable for machine parsing.
+    pub fn summary_line(&self) -> String {
+        format!(
+            "best_of={} human={} computer={} draws={} winner={} rounds={}",
+            self.best_of(),
+            self.human_points,
+            self.computer_points,
+            self.draws,
+            self.game_winner().to_string().to_lowercase(),
+            self.rounds_played()
+        )
+    }
+
+    /// Winner and score of the game as it stands, without the history.
+    pub fn result(&self) -> GameResult {
+        GameResult {
+            winner: self.game_winner(),
+            human_points: self.human_points,
+            computer_points: self.computer_points,
+            draws: self.draws,
+            rounds_played: self.rounds_played(),
+        }
+    }
+
+    /// Player with more points right now, `Draw` when level. Unlike
+    /// [`Game::game_winner`] this ignores the scoring mode.
+    pub fn leader(&self) -> Winner {
+        match self.human_points.cmp(&self.computer_points) {
+            Ordering::Greater => Winner::Human,
+            Ordering::Less => Winner::Computer,
+            Ordering::Equal => Winner::Draw,
+        }
+    }
+
+    /// Difference between the winner's and the loser's points.
+    pub fn margin(&self) -> u8 {
+        self.human_points.abs_diff(self.computer_points)
+    }
+
+    /// Whether the game is over and its winner was at some point behind by
+    /// the largest deficit possible without losing, e.g. 0-2 in a best of 5.
+    pub fn was_comeback(&self) -> bool {
+        let winner = self.game_winner();
+        if !self.is_over() || winner == Winner::Draw {
+            return false;
+        }
+        let (mut winner_points, mut loser_points) = (0u8, 0u8);
+        let mut worst_deficit = 0;
+        for record in &self.history {
+            match record.outcome().winner() {
+                Winner::Draw => continue,
+                round_winner if round_winner == winner => winner_points += 1,
+                _ => loser_points += 1,
+            }
+            worst_deficit = worst_deficit.max(loser_points.saturating_sub(winner_points));
+        }
+        worst_deficit >= self.winning_threshold().saturating_sub(1).max(1)
+    }
+
+    /// The player who won every round played, if one did. A single draw or
+    /// lost round breaks the sweep, and an empty history is none.
+    pub fn is_sweep(&self) -> Option<Winner> {
+        let first = self.history.first()?.outcome().winner();
+        let swept = first != Winner::Draw
+            && self
+                .history
+                .iter()
+                .all(|record| record.outcome().winner() == first);
+        if swept {
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    /// Winner of a match stopped early because its time budget ran out.
+    ///
+    /// The player with more points at that moment wins; equal points, including
+    /// a match stopped before any round was decided, are a draw.
+    pub fn resolve_on_time(&self) -> Winner {
+        self.game_winner()
+    }
+
+    /// Points needed to clinch the game under the active win condition.
+    /// Endless games cannot be clinched, so `u8::MAX` is returned for them.
+    pub fn winning_threshold(&self) -> u8 {
+        match self.win_condition {
+            WinCondition::BestOf => (self.best_of() / 2) + 1,
+            WinCondition::AdaptiveLength { .. } => (self.adaptive_length() / 2) + 1,
+            WinCondition::FirstTo(points) => points,
+            WinCondition::Endless => u8::MAX,
+        }
+    }
+
+    /// Best of rounds of a [`WinCondition::AdaptiveLength`] game with its
+    /// extensions so far.
+    fn adaptive_length(&self) -> u8 {
+        self.adaptive_length.unwrap_or_else(|| self.best_of())
+    }
+
+    /// Makes a [`WinCondition::AdaptiveLength`] game two rounds longer, as
+    /// often as needed, when it would end with the players close. Checked
+    /// after every round; an extension is kept even when the lead widens
+    /// later.
+    fn extend_adaptive_length(&mut self) {
+        let (margin, max_length) = match self.win_condition {
+            WinCondition::AdaptiveLength { margin, max_length } => (margin, max_length),
+            _ => return,
+        };
+        let human = self.half_points(Player::Human);
+        let computer = self.half_points(Player::Computer);
+        let close = human.abs_diff(computer) <= 2 * u16::from(margin);
+        let mut length = self.adaptive_length();
+        loop {
+            let threshold = 2 * u16::from(length / 2 + 1);
+            let would_end = human.max(computer) >= threshold || self.rounds_played() >= length;
+            match length.checked_add(2) {
+                Some(longer) if would_end && close && longer <= max_length => length = longer,
+                _ => break,
+            }
+        }
+        if length != self.best_of() {
+            self.adaptive_length = Some(length);
+        }
+    }
+
+    pub fn enough_points_to_end_game(&self) -> bool {
+        if self.win_condition == WinCondition::Endless {
+            return false;
+        }
+        let threshold = 2 * u16::from(self.winning_threshold());
+        (self.half_points(Player::Human) >= threshold)
+            | (self.half_points(Player::Computer) >= threshold)
+    }
+
+    /// Whether a game with a draw resolution is level, so that one more
+    /// round has to decide it after all best of rounds, see
+    /// [`BestOf::new_even_allowed`].
+    fn needs_deciding_round(&self) -> bool {
+        self.draw_resolution.is_some() && self.human_points == self.computer_points
+    }
+
+    /// Whether another round no longer fits in the `u8` round number or
+    /// scores, which stops even endless games, e.g. after 254 rounds.
+    pub fn is_at_round_limit(&self) -> bool {
+        [
+            self.round.number,
+            self.human_points,
+            self.computer_points,
+            self.draws,
+        ]
+        .iter()
+        .any(|counter| counter.checked_add(1).is_none())
+    }
+
+    /// Whether no more rounds should be played, because someone has enough
+    /// points or all best of rounds were played. Endless games are never over,
+    /// and best of games that don't [stop early](Game::with_stop_early) are
+    /// only over after all rounds. Any game is over once it reaches its
+    /// [maximum rounds](Game::with_max_rounds) or the
+    /// [round limit](Game::is_at_round_limit).
+    pub fn is_over(&self) -> bool {
+        if self.is_at_round_limit()
+            || matches!(self.max_rounds, Some(max) if self.rounds_played() >= max)
+        {
+            return true;
+        }
+        match self.win_condition {
+            WinCondition::BestOf => {
+                (self.stop_early && self.enough_points_to_end_game())
+                    || (self.rounds_played() >= self.best_of() && !self.needs_deciding_round())
+            }
+            WinCondition::AdaptiveLength { .. } => {
+                self.enough_points_to_end_game() || self.rounds_played() >= self.adaptive_length()
+            }
+            WinCondition::FirstTo(_) => self.enough_points_to_end_game(),
+            WinCondition::Endless => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Choice {
+    /// Next choice in the fixed Rock, Paper, Scissors order, wrapping around.
+    pub fn next(&self) -> Choice {
+        match self {
+            Choice::Rock => Choice::Paper,
+            Choice::Paper => Choice::Scissors,
+            Choice::Scissors => Choice::Rock,
+        }
+    }
+
+    /// Whether this choice wins against `other` under the normal rules.
+    pub fn beats(&self, other: &Choice) -> bool {
+        self > other
+    }
+
+    /// Whether this choice wins, loses or ties against `other` under the
+    /// normal rules, e.g. `Rock.play_against(&Scissors)` is a win.
+    pub fn play_against(&self, other: &Choice) -> PlayResult {
+        OUTCOMES[usize::from(self.to_bits())][usize::from(other.to_bits())]
+    }
+
+    /// Compares choices alphabetically by name, ignoring which one beats the
+    /// other, e.g. for `choices.sort_by(Choice::cmp_by_name)`.
+    pub fn cmp_by_name(&self, other: &Choice) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+
+    /// Previous choice in the fixed Rock, Paper, Scissors order, wrapping around.
+    pub fn prev(&self) -> Choice {
+        match self {
+            Choice::Rock => Choice::Scissors,
+            Choice::Paper => Choice::Rock,
+            Choice::Scissors => Choice::Paper,
+        }
+    }
+
+    /// Two bit code of the choice: 0 for Rock, 1 for Paper and 2 for Scissors.
+    pub fn to_bits(&self) -> u8 {
+        match self {
+            Choice::Rock => 0,
+            Choice::Paper => 1,
+            Choice::Scissors => 2,
+        }
+    }
+
+    /// Choice with the two bit code `bits`, see [`Choice::to_bits`].
+    pub fn from_bits(bits: u8) -> Option<Choice> {
+        match bits {
+            0 => Some(Choice::Rock),
+            1 => Some(Choice::Paper),
+            2 => Some(Choice::Scissors),
+            _ => None,
+        }
+    }
+
+    /// Packs choices four to a byte, the first choice in the lowest bits.
+    /// Unused bits of the last byte are filled with the otherwise unused code
+    /// 3, so [`Choice::unpack`] needs no separate length.
+    pub fn pack(choices: &[Choice]) -> Vec<u8> {
+        choices
+            .chunks(4)
+            .map(|chunk| {
+                (0..4).fold(0, |byte, slot| {
+                    let bits = chunk.get(slot).map_or(PACKED_PADDING, Choice::to_bits);
+                    byte | bits << (2 * slot)
+                })
+            })
+            .collect()
+    }
+
+    /// Reverses [`Choice::pack`].
+    pub fn unpack(bytes: &[u8]) -> Result<Vec<Choice>, RpsError> {
+        let mut choices = Vec::with_capacity(bytes.len() * 4);
+        for (index, byte) in bytes.iter().enumerate() {
+            let is_last = index + 1 == bytes.len();
+            let mut padded = false;
+            for slot in 0..4 {
+                match Choice::from_bits(byte >> (2 * slot) & 0b11) {
+                    Some(choice) if !padded => choices.push(choice),
+                    None if is_last && slot > 0 => padded = true,
+                    _ => return Err(RpsError::InvalidPackedChoice(*byte)),
+                }
+            }
+        }
+        Ok(choices)
+    }
+}
+
+/// Result for the first choice under the normal rules, indexed by the
+/// [`Choice::to_bits`] codes of both choices.
+const OUTCOMES: [[PlayResult; 3]; 3] = [
+    [PlayResult::Tie, PlayResult::Lose, PlayResult::Win],
+    [PlayResult::Win, PlayResult::Tie, PlayResult::Lose],
+    [PlayResult::Lose, PlayResult::Win, PlayResult::Tie],
+];
+
+/// Code filling the unused slots of the last byte in [`Choice::pack`].
+const PACKED_PADDING: u8 = 0b11;
+
+impl From<Choice> for u8 {
+    fn from(choice: Choice) -> u8 {
+        choice.to_bits()
+    }
+}
+
+impl Distribution<Choice> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Choice {
+        match rng.gen_range(0..=2) {
+            0 => Choice::Rock,
+            1 => Choice::Paper,
+            _ => Choice::Scissors,
+        }
+    }
+}
+
+/// Levenshtein distance between `a` and `b`, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// `input` without a leading shell prompt and without surrounding quotes.
+fn strip_paste_artifacts(input: &str) -> &str {
+    let input = input.trim_start_matches(['>', '$']).trim_start();
+    for quote in ['"', '\'', '`'] {
+        if let Some(inner) = input
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner.trim();
+        }
+    }
+    input
+}
+
+/// Options controlling how [`Choice::parse_with_options`] matches input.
+/// More options may be added, so start from [`ParseOptions::default`] and
+/// change it with the `with_` methods.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// When set, only the lowercase forms (`rock`, `r`, ...) are accepted.
+    pub case_sensitive: bool,
+    /// When set, the default, a leading `>` or `$` prompt and surrounding
+    /// quotes, as pasted from a terminal, are removed first, so `> rock` and
+    /// `"rock"` are Rock. Otherwise input is matched as is.
+    pub strip_paste_artifacts: bool,
+    /// Language whose words are accepted besides the English ones.
+    pub language: Language,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            case_sensitive: false,
+            strip_paste_artifacts: true,
+            language: Language::default(),
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    pub fn with_strip_paste_artifacts(mut self, strip_paste_artifacts: bool) -> Self {
+        self.strip_paste_artifacts = strip_paste_artifacts;
+        self
+    }
+
+    /// Also accepts the words of `language`.
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+}
+
+impl Choice {
+    /// Parses a list of choices separated by `sep`, e.g. `rock, p, 3`. An
+    /// empty input is an empty list; otherwise the first entry that fails to
+    /// parse is reported with its index.
+    pub fn parse_many(input: &str, sep: char) -> Result<Vec<Choice>, RpsError> {
+        Choice::parse_many_with_options(input, sep, ParseOptions::default())
+    }
+
+    /// Like [`Choice::parse_many`], matching every entry with `options`.
+    pub fn parse_many_with_options(
+        input: &str,
+        sep: char,
+        options: ParseOptions,
+    ) -> Result<Vec<Choice>, RpsError> {
+        if input.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        input
+            .split(sep)
+            .enumerate()
+            .map(|(index, entry)| {
+                Choice::parse_with_options(entry, options).map_err(|error| RpsError::InvalidMove {
+                    index,
+                    error: Box::new(error),
+                })
+            })
+            .collect()
+    }
+
+    pub fn parse_with_options(input: &str, options: ParseOptions) -> Result<Self, RpsError> {
+        let input = input.trim();
+        let stripped = if options.strip_paste_artifacts {
+            strip_paste_artifacts(input)
+        } else {
+            input
+        };
+        let normalized = if options.case_sensitive {
+            stripped.to_string()
+        } else {
+            stripped.to_lowercase()
+        };
+        // Emoji may carry a trailing variation selector, e.g. "✂️".
+        let word = normalized.trim_end_matches('\u{fe0f}');
+        let words = options.language.words();
+        if let Some((_, choice)) = words.iter().find(|(translation, _)| *translation == word) {
+            return Ok(*choice);
+        }
+        match word {
+            "rock" | "r" | "1" | "✊" | "🪨" => Ok(Self::Rock),
+            "paper" | "p" | "2" | "✋" | "📄" => Ok(Self::Paper),
+            "scissors" | "s" | "3" | "✌" | "✂" => Ok(Self::Scissors),
+            _ => Err(RpsError::UnknownChoice {
+                input: input.to_string(),
+                suggestion: Self::suggest(&stripped.to_lowercase()),
+            }),
+        }
+    }
+
+    /// Choice whose name is within two typos of `input`, if any.
+    fn suggest(input: &str) -> Option<Choice> {
+        [Choice::Rock, Choice::Paper, Choice::Scissors]
+            .iter()
+            .map(|choice| {
+                (
+                    *choice,
+                    edit_distance(input, &choice.to_string().to_lowercase()),
+                )
+            })
+            .filter(|(_, distance)| *distance <= 2)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(choice, _)| choice)
+    }
+}
+
+impl FromStr for Choice {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Choice::parse_with_options(s, ParseOptions::default())
+    }
+}
+
+impl TryFrom<String> for Choice {
+    type Error = RpsError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Choice::parse_with_options(&value, ParseOptions::default())
+    }
+}
+
+/// `{}` prints the name of the choice, `{:#}` prints its emoji.
+impl Display for Choice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "{}",
+                match self {
+                    Choice::Paper => "📄",
+                    Choice::Rock => "🪨",
+                    Choice::Scissors => "✂️",
+                }
+            )
+        } else {
+            write!(
+                f,
+                "{}",
+                match self {
+                    Choice::Paper => "Paper",
+                    Choice::Rock => "Rock",
+                    Choice::Scissors => "Scissors",
+                }
+            )
+        }
+    }
+}
+
+/// `a > b` when `a` beats `b`. The order is not transitive, so prefer
+/// [`Choice::play_against`].
+impl PartialOrd for Choice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.play_against(other) {
+            PlayResult::Win => Some(Ordering::Greater),
+            PlayResult::Lose => Some(Ordering::Less),
+            PlayResult::Tie => Some(Ordering::Equal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_beats_scissors() {
+        let rock = Choice::Rock;
+        let scissors = Choice::Scissors;
+        assert!(rock > scissors)
+    }
+
+    #[test]
+    fn paper_beats_rock() {
+        let rock = Choice::Rock;
+        let paper = Choice::Paper;
+        assert!(paper > rock)
+    }
+
+    #[test]
+    fn scissors_beats_paper() {
+        let scissors = Choice::Scissors;
+        let paper = Choice::Paper;
+        assert!(scissors > paper)
+    }
+
+    #[test]
+    fn rock_loses_to_paper() {
+        let rock = Choice::Rock;
+        let paper = Choice::Paper;
+        assert!(rock < paper)
+    }
+
+    #[test]
+    fn paper_loses_to_scissors() {
+        let scissors = Choice::Scissors;
+        let paper = Choice::Paper;
+        assert!(paper < scissors)
+    }
+
+    #[test]
+    fn scissors_loses_to_rock() {
+        let scissors = Choice::Scissors;
+        let rock = Choice::Rock;
+        assert!(scissors < rock)
+    }
+
+    #[test]
+    fn same_choice_is_equal() {
+        let scissors = Choice::Scissors;
+        let paper = Choice::Paper;
+        let rock = Choice::Rock;
+
+        assert!(scissors == scissors);
+        assert!(paper == paper);
+        assert!(rock == rock);
+    }
+
+    #[test]
+    fn human_gets_point() {
+        let mut game = Game::new(None);
+        game.add_point(&Winner::Human);
+        assert_eq!(game.human_points(), 1);
+    }
+
+    #[test]
+    fn computer_gets_point() {
+        let mut game = Game::new(None);
+        game.add_point(&Winner::Computer);
+        assert_eq!(game.computer_points(), 1);
+    }
+
+    #[test]
+    fn should_chose_round_winner() {
+        let game = Game::new(None);
+        let human_choice = Choice::Paper;
+        let computer_choice = Choice::Rock;
+
+        assert_eq!(
+            game.round_winner(&human_choice, &computer_choice),
+            Winner::Human
+        );
+    }
+
+    #[test]
+    fn if_points_are_equal_game_is_drawn() {
+        let mut game = Game::new(None);
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Human);
+        game.add_point(&Winner::Human);
+        assert_eq!(game.game_winner(), Winner::Draw)
+    }
+
+    #[test]
+    #[should_panic]
+    fn on_even_numbers() {
+        BestOf::new(6).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn on_number_less_than_3() {
+        BestOf::new(2).unwrap();
+    }
+
+    #[test]
+    fn should_stop_when_other_player_cant_win_anymore() {
+        let mut game = Game::new(Some(BestOf::default()));
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Computer);
+        assert!(game.enough_points_to_end_game());
+    }
+
+    #[test]
+    fn false_when_not_enough_points_to_end_game_early() {
+        let mut game = Game::new(Some(BestOf::default()));
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Human);
+        game.add_point(&Winner::Human);
+        assert!(!game.enough_points_to_end_game());
+    }
+
+    #[test]
+    fn restore_returns_to_checkpointed_scores() {
+        let mut game = Game::new(None);
+        game.add_point(&Winner::Human);
+        game.round().increase();
+        let checkpoint = game.checkpoint();
+
+        game.add_point(&Winner::Computer);
+        game.add_point(&Winner::Draw);
+        game.round().increase();
+        game.restore(checkpoint);
+
+        assert_eq!(game.human_points(), 1);
+        assert_eq!(game.computer_points(), 0);
+        assert_eq!(game.draws(), 0);
+        assert_eq!(format!("{}", game.round()), "2");
+    }
+
+    #[test]
+    fn restore_drops_rounds_after_checkpoint() {
+        let mut game = Game::with_state(BestOf::default(), 0, 0, 0, 0);
+        let checkpoint = game.checkpoint();
+        game.apply_outcome(RoundOutcome::new(
+            Choice::Rock,
+            Choice::Paper,
+            Winner::Computer,
+        ));
+        game.restore(checkpoint);
+        assert!(game.history().is_empty());
+        assert_eq!(game.round_number(), 0);
+
+        let mut game = Game::new(None);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        let checkpoint = game.checkpoint();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.restore(checkpoint);
+        assert_eq!(game.history().len(), 1);
+        assert_eq!(game.round_number(), 2);
+    }
+
+    #[test]
+    fn summary_line_has_stable_format() {
+        let mut game = Game::new(None);
+        for winner in &[
+            Winner::Human,
+            Winner::Computer,
+            Winner::Human,
+            Winner::Human,
+        ] {
+            game.add_point(winner);
+            game.round().increase();
+        }
+
+        assert_eq!(
+            game.summary_line(),
+            "best_of=5 human=3 computer=1 draws=0 winner=human rounds=4"
+        );
+    }
+
+    #[test]
+    fn best_of_rounds_accepts_odd_number() {
+        let game = Game::best_of_rounds(7).unwrap();
+        assert_eq!(game.best_of(), 7);
+    }
+
+    #[test]
+    fn best_of_rounds_rejects_invalid_number() {
+        assert_eq!(
+            Game::best_of_rounds(4).unwrap_err(),
+            RpsError::InvalidBestOf(4)
+        );
+        assert_eq!(
+            Game::best_of_rounds(1).unwrap_err(),
+            RpsError::InvalidBestOf(1)
+        );
+    }
+
+    #[test]
+    fn next_cycles_through_all_choices() {
+        assert_eq!(Choice::Rock.next(), Choice::Paper);
+        assert_eq!(Choice::Paper.next(), Choice::Scissors);
+        assert_eq!(Choice::Scissors.next(), Choice::Rock);
+    }
+
+    #[test]
+    fn prev_cycles_through_all_choices() {
+        assert_eq!(Choice::Rock.prev(), Choice::Scissors);
+        assert_eq!(Choice::Paper.prev(), Choice::Rock);
+        assert_eq!(Choice::Scissors.prev(), Choice::Paper);
+    }
+
+    #[test]
+    fn play_against_covers_every_pairing() {
+        use Choice::{Paper, Rock, Scissors};
+        use PlayResult::{Lose, Tie, Win};
+
+        for (choice, other, expected) in &[
+            (Rock, Rock, Tie),
+            (Rock, Paper, Lose),
+            (Rock, Scissors, Win),
+            (Paper, Rock, Win),
+            (Paper, Paper, Tie),
+            (Paper, Scissors, Lose),
+            (Scissors, Rock, Lose),
+            (Scissors, Paper, Win),
+            (Scissors, Scissors, Tie),
+        ] {
+            assert_eq!(choice.play_against(other), *expected);
+        }
+    }
+
+    #[test]
+    fn outcome_table_matches_choice_ordering() {
+        let normal = Game::new(None);
+        let mut inverted = Game::new(None).with_rule_schedule(AlternatingRules);
+        inverted.play_round(Choice::Rock, Choice::Rock).unwrap();
+        assert_eq!(inverted.active_rule(), Rule::Inverted);
+
+        for human in &[Choice::Rock, Choice::Paper, Choice::Scissors] {
+            for computer in &[Choice::Rock, Choice::Paper, Choice::Scissors] {
+                let (expected, expected_inverted) = match human.partial_cmp(computer).unwrap() {
+                    Ordering::Greater => (Winner::Human, Winner::Computer),
+                    Ordering::Less => (Winner::Computer, Winner::Human),
+                    Ordering::Equal => (Winner::Draw, Winner::Draw),
+                };
+                assert_eq!(normal.round_winner(human, computer), expected);
+                assert_eq!(inverted.round_winner(human, computer), expected_inverted);
+            }
+        }
+    }
+
+    #[test]
+    fn packed_choices_round_trip() {
+        let cycle = [Choice::Rock, Choice::Paper, Choice::Scissors];
+        for len in 0..=13 {
+            let choices: Vec<Choice> = cycle.iter().cycle().take(len).copied().collect();
+            let packed = Choice::pack(&choices);
+            assert_eq!(packed.len(), len.div_ceil(4));
+            assert_eq!(Choice::unpack(&packed), Ok(choices));
+        }
+        assert_eq!(
+            Choice::pack(&[Choice::Paper, Choice::Scissors]),
+            vec![0b1111_1001]
+        );
+        assert_eq!(u8::from(Choice::Scissors), 2);
+    }
+
+    #[test]
+    fn malformed_packed_choices_are_rejected() {
+        // Padding before the last byte, a padding-only byte and a choice
+        // after padding.
+        let cases: [(&[u8], u8); 3] = [
+            (&[0b1111_1001, 0], 0b1111_1001),
+            (&[0xff], 0xff),
+            (&[0b0011_1100], 0b0011_1100),
+        ];
+        for (bytes, bad) in &cases {
+            assert_eq!(
+                Choice::unpack(bytes),
+                Err(RpsError::InvalidPackedChoice(*bad))
+            );
+        }
+        assert_eq!(
+            RpsError::InvalidPackedChoice(0xff).to_string(),
+            "Invalid packed choices in byte 0b11111111"
+        );
+    }
+
+    #[test]
+    fn case_insensitive_parsing_is_default() {
+        assert_eq!(Choice::try_from("ROCK\n".to_string()), Ok(Choice::Rock));
+        assert_eq!(Choice::try_from("p\n".to_string()), Ok(Choice::Paper));
+    }
+
+    #[test]
+    fn pasted_artifacts_are_stripped_by_default() {
+        for input in &["\"rock\"", "'rock'", "> rock", "$ 'Rock'\n", ">>r"] {
+            assert_eq!(input.parse::<Choice>(), Ok(Choice::Rock), "{}", input);
+        }
+        let options = ParseOptions::default().with_strip_paste_artifacts(false);
+        assert!(Choice::parse_with_options("> rock", options).is_err());
+        assert!(Choice::parse_with_options("'rock'", options).is_err());
+        assert!("\"rock".parse::<Choice>().is_err());
+    }
+
+    #[test]
+    fn case_sensitive_parsing_accepts_exact_forms() {
+        let options = ParseOptions::default().with_case_sensitive(true);
+        assert_eq!(
+            Choice::parse_with_options("scissors\n", options),
+            Ok(Choice::Scissors)
+        );
+        assert_eq!(Choice::parse_with_options("r", options), Ok(Choice::Rock));
+    }
+
+    #[test]
+    fn case_sensitive_parsing_rejects_uppercase() {
+        let options = ParseOptions::default().with_case_sensitive(true);
+        assert_eq!(
+            Choice::parse_with_options("Rock\n", options),
+            Err(RpsError::UnknownChoice {
+                input: "Rock".to_string(),
+                suggestion: Some(Choice::Rock),
+            })
+        );
+        assert!(Choice::parse_with_options("P", options).is_err());
+    }
+
+    #[test]
+    fn momentum_follows_recent_rounds() {
+        let mut game = Game::best_of_rounds(9).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+
+        assert_eq!(game.momentum(3), Winner::Human);
+        assert_eq!(game.momentum(6), Winner::Draw);
+        assert_eq!(game.momentum(0), Winner::Draw);
+    }
+
+    #[test]
+    fn replayed_draw_is_not_recorded() {
+        let mut game = Game::new(None).with_draw_resolution(DrawResolution::Replay);
+        let outcome = game.play_round(Choice::Rock, Choice::Rock).unwrap();
+
+        assert_eq!(outcome.winner(), Winner::Draw);
+        assert!(game.history().is_empty());
+        assert_eq!(game.draws(), 0);
+        assert_eq!(format!("{}", game.round()), "1");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn random_draw_resolution_picks_a_winner() {
+        let mut game = Game::new(None).with_draw_resolution(DrawResolution::Random);
+        let outcome = game.play_round(Choice::Rock, Choice::Rock).unwrap();
+
+        assert_ne!(outcome.winner(), Winner::Draw);
+        assert_eq!(game.human_points() + game.computer_points(), 1);
+        assert_eq!(game.draws(), 0);
+    }
+
+    #[test]
+    fn random_draw_resolution_flips_the_given_rng() {
+        use rand::rngs::mock::StepRng;
+
+        let mut game = Game::new(None).with_draw_resolution(DrawResolution::Random);
+        let heads = game
+            .play_round_with_rng(Choice::Rock, Choice::Rock, &mut StepRng::new(u64::MAX, 0))
+            .unwrap();
+        let tails = game
+            .play_round_with_rng(Choice::Rock, Choice::Rock, &mut StepRng::new(0, 0))
+            .unwrap();
+
+        assert_eq!(heads.winner(), Winner::Human);
+        assert_eq!(tails.winner(), Winner::Computer);
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn random_draw_resolution_needs_an_rng_without_std() {
+        let mut game = Game::new(None).with_draw_resolution(DrawResolution::Random);
+        assert_eq!(
+            game.play_round(Choice::Rock, Choice::Rock),
+            Err(RpsError::RngRequired)
+        );
+        assert!(game.play_round(Choice::Rock, Choice::Paper).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn seeded_random_draw_resolution_is_reproducible() {
+        use rand::SeedableRng;
+
+        let winners = |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            let mut game = Game::new(Some(BestOf::new(9).unwrap()))
+                .with_draw_resolution(DrawResolution::Random);
+            (0..5)
+                .map(|_| {
+                    game.play_round_with_rng(Choice::Rock, Choice::Rock, &mut rng)
+                        .unwrap()
+                        .winner()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(winners(11), winners(11));
+        assert!(!winners(11).contains(&Winner::Draw));
+    }
+
+    #[test]
+    fn favor_player_draw_resolution_awards_human() {
+        let mut game = Game::new(None).with_draw_resolution(DrawResolution::FavorPlayer);
+        let outcome = game.play_round(Choice::Rock, Choice::Rock).unwrap();
+
+        assert_eq!(outcome.winner(), Winner::Human);
+        assert_eq!(game.human_points(), 1);
+        assert_eq!(game.history()[0].outcome().winner(), Winner::Human);
+    }
+
+    #[test]
+    fn games_with_same_state_are_equal() {
+        let mut played = Game::new(None);
+        played.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        played.play_round(Choice::Paper, Choice::Paper).unwrap();
+        let built = Game::with_state(BestOf::default(), 1, 0, 1, 3);
+
+        assert_eq!(played, built);
+        assert_ne!(played, Game::with_state(BestOf::default(), 0, 1, 1, 3));
+    }
+
+    #[test]
+    fn tie_on_timeout_is_a_draw() {
+        let mut game = Game::new(None);
+        assert_eq!(game.resolve_on_time(), Winner::Draw);
+
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        assert_eq!(game.resolve_on_time(), Winner::Draw);
+
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        assert_eq!(game.resolve_on_time(), Winner::Human);
+    }
+
+    #[test]
+    fn choice_alternate_format_prints_emoji() {
+        assert_eq!(format!("{}", Choice::Rock), "Rock");
+        assert_eq!(format!("{:#}", Choice::Rock), "🪨");
+        assert_eq!(format!("{:#}", Choice::Paper), "📄");
+        assert_eq!(format!("{:#}", Choice::Scissors), "✂️");
+    }
+
+    #[test]
+    fn winner_alternate_format_is_decorated() {
+        assert_eq!(format!("{}", Winner::Human), "Human");
+        assert_eq!(format!("{:#}", Winner::Human), "🏆 Human");
+        assert_eq!(format!("{:#}", Winner::Computer), "🏆 Computer");
+        assert_eq!(format!("{:#}", Winner::Draw), "🤝 Draw");
+    }
+
+    #[test]
+    fn alternating_rules_invert_second_round() {
+        let mut game = Game::new(None).with_rule_schedule(AlternatingRules);
+        let first = game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        assert_eq!(game.active_rule(), Rule::Inverted);
+        let second = game.play_round(Choice::Rock, Choice::Paper).unwrap();
+
+        assert_eq!(first.winner(), Winner::Computer);
+        assert_eq!(second.winner(), Winner::Human);
+        assert_eq!(game.active_rule(), Rule::Normal);
+    }
+
+    #[test]
+    fn even_best_of_allowed_with_tie_break() {
+        let best_of = BestOf::new_even_allowed(4, Some(DrawResolution::Replay)).unwrap();
+        let mut game = Game::new(Some(best_of));
+        assert_eq!(game.draw_resolution(), Some(DrawResolution::Replay));
+        game.add_point(&Winner::Human);
+        game.add_point(&Winner::Human);
+        assert!(!game.enough_points_to_end_game());
+        game.add_point(&Winner::Human);
+        assert!(game.enough_points_to_end_game());
+    }
+
+    #[test]
+    fn level_even_best_of_is_decided_by_another_round() {
+        let best_of = BestOf::new_even_allowed(4, Some(DrawResolution::Replay)).unwrap();
+        let mut game = Game::new(Some(best_of));
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+        assert_eq!((game.human_points(), game.computer_points()), (2, 2));
+        assert!(!game.is_over());
+
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        assert!(!game.is_over());
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        assert!(game.is_over());
+        assert_eq!(game.game_winner(), Winner::Human);
+    }
+
+    #[test]
+    fn even_best_of_rejected_without_tie_break() {
+        assert_eq!(
+            BestOf::new_even_allowed(4, None).unwrap_err(),
+            RpsError::InvalidBestOf(4)
+        );
+        assert!(BestOf::new_even_allowed(5, None).is_ok());
+        assert!(BestOf::new_even_allowed(1, Some(DrawResolution::Random)).is_err());
+    }
+
+    #[test]
+    fn best_of_parses_first_to_shorthand() {
+        assert_eq!("ft3".parse::<BestOf>(), Ok(BestOf(5, None)));
+        assert_eq!("first-to-2".parse::<BestOf>(), Ok(BestOf(3, None)));
+        assert_eq!(
+            "first-to-0".parse::<BestOf>(),
+            Err(RpsError::InvalidBestOf(0))
+        );
+        assert!("ft1".parse::<BestOf>().is_err());
+        assert!(matches!(
+            "ft200".parse::<BestOf>(),
+            Err(RpsError::BestOfTooLarge { .. })
+        ));
+        assert!(matches!(
+            "ftx".parse::<BestOf>(),
+            Err(RpsError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn best_of_parse_error_keeps_its_source() {
+        use std::error::Error;
+
+        let error = "five".parse::<BestOf>().unwrap_err();
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+        assert!(BestOf::new(4).unwrap_err().source().is_none());
+    }
+
+    #[test]
+    fn endless_game_is_never_over() {
+        let mut game = Game::new(None).with_win_condition(WinCondition::Endless);
+        for _ in 0..10 {
+            game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        }
+        assert!(!game.enough_points_to_end_game());
+        assert!(!game.is_over());
+        assert_eq!(game.human_points(), 10);
+    }
+
+    #[test]
+    fn endless_game_stops_at_round_limit() {
+        let mut game = Game::new(None).with_win_condition(WinCondition::Endless);
+        let mut played = 0;
+        for _ in 0..300 {
+            if game.play_round(Choice::Rock, Choice::Rock).is_err() {
+                break;
+            }
+            played += 1;
+        }
+
+        assert_eq!(played, 254);
+        assert!(game.is_at_round_limit());
+        assert!(game.is_over());
+        assert_eq!(game.draws(), 254);
         assert_eq!(
-            game.round_winner(&human_choice, &computer_choice),
-            Winner::Human
+            game.play_round(Choice::Rock, Choice::Rock),
+            Err(RpsError::GameOver)
         );
     }
 
     #[test]
-    fn if_points_are_equal_game_is_drawn() {
+    fn endless_game_stops_at_max_rounds() {
+        let mut game = Game::new(None)
+            .with_win_condition(WinCondition::Endless)
+            .with_max_rounds(4);
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+            assert!(!game.is_over());
+        }
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+
+        assert!(game.is_over());
+        assert_eq!(game.rounds_played(), 4);
+        assert_eq!(game.game_winner(), Winner::Computer);
+    }
+
+    #[test]
+    fn best_of_game_is_over_after_all_rounds() {
+        let mut game = Game::best_of_rounds(3).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert!(!game.is_over());
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn digits_select_choices() {
+        assert_eq!(Choice::try_from("1\n".to_string()), Ok(Choice::Rock));
+        assert_eq!(Choice::try_from("2\n".to_string()), Ok(Choice::Paper));
+        assert_eq!(Choice::try_from("3\n".to_string()), Ok(Choice::Scissors));
+    }
+
+    #[test]
+    fn digits_outside_keypad_range_are_rejected() {
+        assert!(Choice::try_from("0\n".to_string()).is_err());
+        assert!(Choice::try_from("4\n".to_string()).is_err());
+    }
+
+    #[test]
+    fn named_winner_replaces_computer_only() {
+        assert_eq!(Winner::Computer.named("HAL").to_string(), "HAL");
+        assert_eq!(format!("{:#}", Winner::Computer.named("HAL")), "🏆 HAL");
+        assert_eq!(Winner::Human.named("HAL").to_string(), "Human");
+        assert_eq!(Winner::Draw.named("HAL").to_string(), "Draw");
+    }
+
+    #[test]
+    fn reset_starts_game_over() {
+        let mut game = Game::best_of_rounds(3).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.reset();
+
+        assert_eq!(game, Game::best_of_rounds(3).unwrap());
+        assert!(game.history().is_empty());
+    }
+
+    #[test]
+    fn read_accessors_describe_mid_game_state() {
+        let mut game = Game::new(None).with_draw_resolution(DrawResolution::FavorPlayer);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+
+        assert_eq!(game.human_points(), 1);
+        assert_eq!(game.computer_points(), 1);
+        assert_eq!(game.draws(), 0);
+        assert_eq!(game.best_of(), 5);
+        assert_eq!(game.round_number(), 3);
+        assert_eq!(game.rounds_remaining(), Some(3));
+        assert_eq!(game.history().len(), 2);
+        assert_eq!(game.win_condition(), WinCondition::BestOf);
+        assert_eq!(game.draw_resolution(), Some(DrawResolution::FavorPlayer));
+        assert_eq!(game.active_rule(), Rule::Normal);
+        assert_eq!(game.game_winner(), Winner::Draw);
+        assert!(!game.is_over());
+
+        let snapshot = game.checkpoint();
+        assert_eq!(snapshot.human_points(), 1);
+        assert_eq!(snapshot.computer_points(), 1);
+        assert_eq!(snapshot.draws(), 0);
+        assert_eq!(snapshot.round(), 3);
+
+        let endless = Game::new(None).with_win_condition(WinCondition::Endless);
+        assert_eq!(endless.rounds_remaining(), None);
+    }
+
+    #[test]
+    fn loser_choice_follows_winner() {
         let mut game = Game::new(None);
-        game.add_point(&Winner::Computer);
-        game.add_point(&Winner::Computer);
-        game.add_point(&Winner::Human);
-        game.add_point(&Winner::Human);
-        assert_eq!(game.game_winner(), Winner::Draw)
+        let human_win = game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        let computer_win = game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        let draw = game.play_round(Choice::Paper, Choice::Paper).unwrap();
+
+        assert_eq!(human_win.loser_choice(), Some(Choice::Scissors));
+        assert_eq!(computer_win.loser_choice(), Some(Choice::Rock));
+        assert_eq!(draw.loser_choice(), None);
     }
 
     #[test]
-    #[should_panic]
-    fn on_even_numbers() {
-        BestOf::new(6).unwrap();
+    fn emoji_select_choices() {
+        for (input, choice) in &[
+            ("✊", Choice::Rock),
+            ("🪨\n", Choice::Rock),
+            ("✋\n", Choice::Paper),
+            (" 📄 ", Choice::Paper),
+            ("✌\n", Choice::Scissors),
+            ("✌️", Choice::Scissors),
+            ("✂\n", Choice::Scissors),
+            ("✂️\n", Choice::Scissors),
+        ] {
+            assert_eq!(Choice::try_from(input.to_string()), Ok(*choice));
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn on_number_less_than_3() {
-        BestOf::new(2).unwrap();
+    fn alternate_display_parses_back() {
+        for choice in &[Choice::Rock, Choice::Paper, Choice::Scissors] {
+            assert_eq!(format!("{:#}", choice).parse::<Choice>(), Ok(*choice));
+        }
     }
 
     #[test]
-    fn should_stop_when_other_player_cant_win_anymore() {
-        let mut game = Game::new(Some(BestOf::default()));
-        game.add_point(&Winner::Computer);
-        game.add_point(&Winner::Computer);
-        game.add_point(&Winner::Computer);
+    fn applied_outcomes_update_scoreboard() {
+        let mut game = Game::new(None);
+        game.apply_outcome(RoundOutcome::new(Choice::Rock, Choice::Rock, Winner::Human));
+        game.apply_outcome(RoundOutcome::new(
+            Choice::Paper,
+            Choice::Scissors,
+            Winner::Computer,
+        ));
+        game.apply_outcome(RoundOutcome::new(Choice::Paper, Choice::Rock, Winner::Draw));
+
+        assert_eq!(game.human_points(), 1);
+        assert_eq!(game.computer_points(), 1);
+        assert_eq!(game.draws(), 1);
+        assert_eq!(game.round_number(), 4);
+        assert_eq!(game.history()[0].outcome().winner(), Winner::Human);
+    }
+
+    #[test]
+    fn parsers_reject_garbage_without_panicking() {
+        for input in &[
+            "",
+            "\n",
+            "\0",
+            "999999999999",
+            "-1",
+            "ロック",
+            "r\r\n\r\n",
+            "\u{fe0f}",
+        ] {
+            let _ = Choice::try_from(input.to_string());
+            let _ = input.parse::<BestOf>();
+            let _ = input.parse::<Winner>();
+        }
+        assert_eq!(Choice::try_from("r\r\n".to_string()), Ok(Choice::Rock));
+        assert!("256".parse::<BestOf>().is_err());
+    }
+
+    #[test]
+    fn winning_threshold_follows_win_condition() {
+        assert_eq!(Game::best_of_rounds(3).unwrap().winning_threshold(), 2);
+        assert_eq!(Game::best_of_rounds(5).unwrap().winning_threshold(), 3);
+
+        let mut first_to_4 = Game::new(None).with_win_condition(WinCondition::FirstTo(4));
+        assert_eq!(first_to_4.winning_threshold(), 4);
+        for _ in 0..3 {
+            first_to_4
+                .play_round(Choice::Rock, Choice::Scissors)
+                .unwrap();
+            first_to_4.play_round(Choice::Rock, Choice::Rock).unwrap();
+        }
+        assert!(!first_to_4.is_over());
+        first_to_4
+            .play_round(Choice::Rock, Choice::Scissors)
+            .unwrap();
+        assert!(first_to_4.is_over());
+    }
+
+    #[test]
+    fn close_adaptive_game_is_extended() {
+        let adaptive = WinCondition::AdaptiveLength {
+            margin: 1,
+            max_length: 5,
+        };
+        let mut game = Game::best_of_rounds(3)
+            .unwrap()
+            .with_win_condition(adaptive);
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+        assert!(!game.is_over());
+        assert_eq!(game.winning_threshold(), 3);
+        assert_eq!(game.rounds_remaining(), Some(2));
+
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert!(game.is_over());
+        assert_eq!(game.game_winner(), Winner::Human);
+    }
+
+    #[test]
+    fn adaptive_extension_is_kept_when_the_lead_widens() {
+        let adaptive = WinCondition::AdaptiveLength {
+            margin: 0,
+            max_length: 9,
+        };
+        let mut game = Game::best_of_rounds(3)
+            .unwrap()
+            .with_win_condition(adaptive);
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Rock),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+        assert_eq!(game.rounds_remaining(), Some(2));
+
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert!(!game.is_over());
+        assert_eq!(game.winning_threshold(), 3);
+        assert_eq!(game.rounds_remaining(), Some(1));
+
+        let checkpoint = game.checkpoint();
+        game.reset();
+        assert_eq!(game.winning_threshold(), 2);
+        game.restore(checkpoint);
+        assert_eq!(game.winning_threshold(), 3);
+
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert!(game.is_over());
+        assert_eq!(game.game_winner(), Winner::Human);
+    }
+
+    #[test]
+    fn adaptive_blowout_is_not_extended() {
+        let adaptive = WinCondition::AdaptiveLength {
+            margin: 1,
+            max_length: 9,
+        };
+        let mut game = Game::best_of_rounds(3)
+            .unwrap()
+            .with_win_condition(adaptive);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+
+        assert!(game.is_over());
+        assert_eq!(game.winning_threshold(), 2);
+    }
+
+    #[test]
+    fn best_of_is_limited_to_default_max() {
+        assert_eq!(BestOf::new(99).unwrap(), BestOf::new_unchecked(99).unwrap());
+        assert_eq!(
+            BestOf::new(101).unwrap_err(),
+            RpsError::BestOfTooLarge {
+                value: 101,
+                max: 99
+            }
+        );
+        assert!(BestOf::new(100).is_err());
+        assert_eq!(
+            BestOf::new_unchecked(255).unwrap(),
+            BestOf::new_unchecked(255).unwrap()
+        );
+    }
+
+    #[test]
+    fn best_of_accepts_custom_max() {
+        assert!(BestOf::with_max(7, 7).is_ok());
+        assert_eq!(
+            BestOf::with_max(9, 7).unwrap_err(),
+            RpsError::BestOfTooLarge { value: 9, max: 7 }
+        );
+        assert_eq!(
+            Game::new(Some(BestOf::new_unchecked(255).unwrap())).best_of(),
+            255
+        );
+    }
+
+    #[test]
+    fn rounds_played_counts_completed_rounds() {
+        let mut game = Game::best_of_rounds(5).unwrap();
+        assert_eq!(game.rounds_played(), 0);
+        assert_eq!(game.round_number(), 1);
+
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Paper, Choice::Scissors).unwrap();
+
+        assert_eq!(game.rounds_played(), 3);
+        assert_eq!(game.round_number(), 4);
+    }
+
+    #[test]
+    fn rounds_played_follows_the_round_number_of_restored_state() {
+        let best_of = BestOf::new(5).unwrap();
+        let game = Game::with_state(best_of, 2, 1, 0, 4);
+        assert_eq!(game.rounds_played(), 3);
+        assert_eq!(game.rounds_remaining(), Some(2));
+        assert!(game.summary_line().ends_with("rounds=3"));
+
+        let game = Game::with_state(BestOf::new(3).unwrap(), 1, 1, 1, 4);
+        assert!(game.is_over());
+
+        let game = Game::with_state(best_of, 0, 0, 0, 0);
+        assert_eq!(game.rounds_played(), 0);
+        assert!(game.summary_line().ends_with("rounds=0"));
+    }
+
+    #[test]
+    fn winner_as_emoji() {
+        assert_eq!(Winner::Human.as_emoji(), "🧑");
+        assert_eq!(Winner::Computer.as_emoji(), "🤖");
+        assert_eq!(Winner::Draw.as_emoji(), "🤝");
+    }
+
+    #[test]
+    fn typos_suggest_the_closest_choice() {
+        assert_eq!(
+            "rok".parse::<Choice>(),
+            Err(RpsError::UnknownChoice {
+                input: "rok".to_string(),
+                suggestion: Some(Choice::Rock),
+            })
+        );
+        assert_eq!(
+            "papwr".parse::<Choice>().unwrap_err().to_string(),
+            "Unknown choice 'papwr', did you mean Paper?"
+        );
+        assert_eq!(
+            "xyz".parse::<Choice>(),
+            Err(RpsError::UnknownChoice {
+                input: "xyz".to_string(),
+                suggestion: None,
+            })
+        );
+    }
+
+    #[test]
+    fn half_point_draws_add_to_both_scores() {
+        let mut game = Game::new(None).with_scoring(Scoring::HalfPointDraws);
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Paper, Choice::Paper).unwrap();
+
+        assert_eq!(game.human_score(), 2.0);
+        assert_eq!(game.computer_score(), 1.0);
+    }
+
+    #[test]
+    fn half_point_draws_break_ties_by_latest_decisive_round() {
+        let mut game =
+            Game::new(Some(BestOf::new(9).unwrap())).with_scoring(Scoring::HalfPointDraws);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Paper, Choice::Paper).unwrap();
+        game.play_round(Choice::Scissors, Choice::Scissors).unwrap();
+
+        assert_eq!(game.human_score(), game.computer_score());
+        assert_eq!(game.game_winner(), Winner::Computer);
+        assert_eq!(
+            game.with_scoring(Scoring::WinsOnly).game_winner(),
+            Winner::Draw
+        );
+    }
+
+    #[test]
+    fn half_point_draws_count_towards_first_to() {
+        let mut game = Game::new(None)
+            .with_win_condition(WinCondition::FirstTo(2))
+            .with_scoring(Scoring::HalfPointDraws);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        assert!(!game.is_over());
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        assert!(game.is_over());
+        assert_eq!(game.game_winner(), Winner::Human);
+    }
+
+    #[test]
+    fn streak_bonus_escalates_consecutive_wins() {
+        let mut game = Game::new(Some(BestOf::new(11).unwrap())).with_scoring(Scoring::StreakBonus);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert_eq!(game.human_score(), 3.0);
+        assert!(!game.is_over());
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+
+        assert_eq!(game.human_score(), 6.0);
+        assert!(game.is_over());
+        assert_eq!(game.game_winner(), Winner::Human);
+    }
+
+    #[test]
+    fn streak_bonus_counts_points_without_history() {
+        let mut game = Game::with_state(BestOf::new(11).unwrap(), 2, 1, 0, 4)
+            .with_scoring(Scoring::StreakBonus);
+        assert_eq!(game.human_score(), 2.0);
+        assert_eq!(game.computer_score(), 1.0);
+
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert_eq!(game.human_score(), 5.0);
+        assert_eq!(game.game_winner(), Winner::Human);
+    }
+
+    #[test]
+    fn streak_bonus_restarts_after_draw_or_loss() {
+        let mut game = Game::new(Some(BestOf::new(11).unwrap())).with_scoring(Scoring::StreakBonus);
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Rock),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+
+        assert_eq!(game.human_score(), 5.0);
+        assert_eq!(game.computer_score(), 1.0);
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn rounds_describe_why_they_were_won() {
+        let cases = [
+            (
+                Choice::Rock,
+                Choice::Scissors,
+                "you win (rock crushes scissors)",
+            ),
+            (
+                Choice::Scissors,
+                Choice::Rock,
+                "the computer wins (rock crushes scissors)",
+            ),
+            (Choice::Paper, Choice::Rock, "you win (paper covers rock)"),
+            (
+                Choice::Rock,
+                Choice::Paper,
+                "the computer wins (paper covers rock)",
+            ),
+            (
+                Choice::Scissors,
+                Choice::Paper,
+                "you win (scissors cut paper)",
+            ),
+            (
+                Choice::Paper,
+                Choice::Scissors,
+                "the computer wins (scissors cut paper)",
+            ),
+        ];
+        for (human, computer, result) in &cases {
+            let mut game = Game::new(None);
+            game.play_round(*human, *computer).unwrap();
+            assert_eq!(
+                game.history()[0].describe(),
+                format!(
+                    "Round 1: you played {}, the computer played {} — {}.",
+                    human, computer, result
+                )
+            );
+        }
+
+        let mut game = Game::new(None).with_rule_schedule(AlternatingRules);
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        assert_eq!(
+            game.history()[0].describe(),
+            "Round 1: you played Rock, the computer played Rock — it's a draw."
+        );
+        assert_eq!(
+            game.history()[1].describe(),
+            "Round 2: you played Rock, the computer played Paper — you win."
+        );
+    }
+
+    #[test]
+    fn game_without_early_stop_plays_every_round() {
+        let mut game = Game::new(None).with_stop_early(false);
+        for _ in 0..3 {
+            game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        }
         assert!(game.enough_points_to_end_game());
+        assert!(!game.is_over());
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+
+        assert!(game.is_over());
+        assert_eq!(
+            game.play_round(Choice::Rock, Choice::Paper),
+            Err(RpsError::GameOver)
+        );
+        assert_eq!(game.game_winner(), Winner::Human);
     }
 
     #[test]
-    fn false_when_not_enough_points_to_end_game_early() {
-        let mut game = Game::new(Some(BestOf::default()));
-        game.add_point(&Winner::Computer);
-        game.add_point(&Winner::Computer);
-        game.add_point(&Winner::Human);
-        game.add_point(&Winner::Human);
-        assert!(!game.enough_points_to_end_game());
+    fn empty_games_are_rejected() {
+        for game in &[
+            Game::new(None),
+            Game::new(None)
+                .try_with_win_condition(WinCondition::FirstTo(1))
+                .unwrap(),
+            Game::new(Some(BestOf::new_unchecked(1).unwrap())),
+            Game::new(None).try_with_max_rounds(1).unwrap(),
+        ] {
+            assert!(!game.is_over());
+        }
+
+        assert_eq!(BestOf::new_unchecked(0), Err(RpsError::EmptyGame));
+        assert_eq!(
+            Game::new(None)
+                .try_with_win_condition(WinCondition::FirstTo(0))
+                .unwrap_err(),
+            RpsError::EmptyGame
+        );
+        assert_eq!(
+            Game::new(None).try_with_max_rounds(0).unwrap_err(),
+            RpsError::EmptyGame
+        );
+    }
+
+    #[test]
+    fn rules_list_every_decisive_pairing_once() {
+        for winner in &[Choice::Rock, Choice::Paper, Choice::Scissors] {
+            for loser in &[Choice::Rock, Choice::Paper, Choice::Scissors] {
+                let entries = RULES
+                    .iter()
+                    .filter(|entry| entry.winner == *winner && entry.loser == *loser)
+                    .count();
+                let expected = usize::from(winner.beats(loser));
+                assert_eq!(entries, expected, "{} against {}", winner, loser);
+            }
+        }
+        assert!(RULES.iter().all(|entry| !entry.verb.is_empty()));
+    }
+
+    #[test]
+    fn choices_sort_by_name() {
+        let mut choices = vec![Choice::Scissors, Choice::Rock, Choice::Paper, Choice::Rock];
+        choices.sort_by(Choice::cmp_by_name);
+        assert_eq!(
+            choices,
+            vec![Choice::Paper, Choice::Rock, Choice::Rock, Choice::Scissors]
+        );
+    }
+
+    #[test]
+    fn default_game_is_fresh_best_of_five() {
+        let game = Game::default();
+        assert_eq!(game.best_of(), 5);
+        assert_eq!(game.human_points(), 0);
+        assert_eq!(game.computer_points(), 0);
+        assert_eq!(game.round_number(), 1);
+    }
+
+    #[test]
+    fn playing_a_finished_game_fails() {
+        let mut game = Game::best_of_rounds(3).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+
+        assert_eq!(
+            game.play_round(Choice::Paper, Choice::Rock),
+            Err(RpsError::GameOver)
+        );
+        assert_eq!(game.human_points(), 2);
+        assert_eq!(game.rounds_played(), 2);
+    }
+
+    #[test]
+    fn parse_many_splits_and_reports_bad_entry() {
+        assert_eq!(
+            Choice::parse_many("rock, p ,3", ','),
+            Ok(vec![Choice::Rock, Choice::Paper, Choice::Scissors])
+        );
+        assert_eq!(Choice::parse_many("", ','), Ok(Vec::new()));
+        assert_eq!(Choice::parse_many("  ", ';'), Ok(Vec::new()));
+
+        match Choice::parse_many("r;s;xyz;p", ';') {
+            Err(RpsError::InvalidMove { index, error }) => {
+                assert_eq!(index, 2);
+                assert_eq!(error.to_string(), "Unknown choice 'xyz'");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn leader_has_more_points() {
+        let best_of = BestOf::new(5).unwrap();
+        assert_eq!(
+            Game::with_state(best_of, 2, 1, 0, 4).leader(),
+            Winner::Human
+        );
+        assert_eq!(
+            Game::with_state(best_of, 0, 1, 2, 4).leader(),
+            Winner::Computer
+        );
+        assert_eq!(Game::with_state(best_of, 1, 1, 1, 4).leader(), Winner::Draw);
+    }
+
+    #[test]
+    fn margin_is_the_point_difference() {
+        let blowout = Game::with_state(BestOf::new(5).unwrap(), 0, 3, 0, 4);
+        assert_eq!(blowout.margin(), 3);
+        let nail_biter = Game::with_state(BestOf::new(5).unwrap(), 3, 2, 0, 6);
+        assert_eq!(nail_biter.margin(), 1);
+    }
+
+    #[test]
+    fn winning_from_the_largest_deficit_is_a_comeback() {
+        let mut game = Game::new(None);
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        assert!(!game.was_comeback());
+        for _ in 0..3 {
+            game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        }
+        assert_eq!((game.human_points(), game.computer_points()), (3, 2));
+        assert!(game.was_comeback());
+
+        let mut game = Game::new(None);
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Paper),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+        assert!(!game.was_comeback());
+    }
+
+    #[test]
+    fn winning_every_round_is_a_sweep() {
+        let mut game = Game::new(None);
+        assert_eq!(game.is_sweep(), None);
+        for _ in 0..3 {
+            game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        }
+        assert_eq!(game.is_sweep(), Some(Winner::Human));
+
+        let mut game = Game::new(None);
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+        assert!(game.is_over());
+        assert_eq!(game.is_sweep(), None);
+
+        let mut game = Game::new(None);
+        for _ in 0..3 {
+            game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        }
+        assert_eq!(game.is_sweep(), Some(Winner::Computer));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn pairings_can_be_tallied() {
+        use std::collections::HashMap;
+
+        let mut game = Game::new(None).with_win_condition(WinCondition::Endless);
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Paper, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Scissors, Choice::Rock),
+            (Choice::Rock, Choice::Scissors),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+
+        let mut tally: HashMap<(Choice, Choice), u32> = HashMap::new();
+        for record in game.history() {
+            *tally.entry(record.pairing_key()).or_default() += 1;
+        }
+        assert_eq!(tally.len(), 3);
+        assert_eq!(tally[&(Choice::Rock, Choice::Scissors)], 3);
+        assert_eq!(tally[&(Choice::Scissors, Choice::Rock)], 1);
+        assert_eq!(tally.get(&(Choice::Scissors, Choice::Paper)), None);
+    }
+
+    #[test]
+    fn tally_counts_winners() {
+        let mut tally = vec![Winner::Human, Winner::Draw, Winner::Human]
+            .into_iter()
+            .collect::<ScoreTally>();
+        tally.extend(vec![Winner::Computer, Winner::Human]);
+
+        assert_eq!(
+            tally,
+            ScoreTally {
+                human: 3,
+                computer: 1,
+                draws: 1,
+            }
+        );
+        assert_eq!(tally.total(), 5);
+        assert_eq!(
+            core::iter::empty().collect::<ScoreTally>(),
+            ScoreTally::default()
+        );
     }
 }
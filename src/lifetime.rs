@@ -0,0 +1,202 @@
+use crate::{Choice, RpsError, ScoreTally, Winner};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// One line of a game log: a [`Game::summary_line`](crate::Game::summary_line)
+/// optionally followed by the human's choice counts, e.g.
+/// `best_of=5 human=3 computer=1 draws=0 winner=human rounds=4 rock=2 paper=1 scissors=1`.
+///
+/// Keys the summary format doesn't know, like a leading `time=`, are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoggedGame {
+    best_of: u8,
+    human_points: u8,
+    computer_points: u8,
+    draws: u8,
+    winner: Winner,
+    rounds: u8,
+    choice_counts: HashMap<Choice, u32>,
+}
+
+impl LoggedGame {
+    pub fn best_of(&self) -> u8 {
+        self.best_of
+    }
+
+    pub fn human_points(&self) -> u8 {
+        self.human_points
+    }
+
+    pub fn computer_points(&self) -> u8 {
+        self.computer_points
+    }
+
+    pub fn draws(&self) -> u8 {
+        self.draws
+    }
+
+    pub fn winner(&self) -> Winner {
+        self.winner
+    }
+
+    pub fn rounds(&self) -> u8 {
+        self.rounds
+    }
+
+    /// How many times the human played each choice, empty when not logged.
+    pub fn choice_counts(&self) -> &HashMap<Choice, u32> {
+        &self.choice_counts
+    }
+}
+
+impl FromStr for LoggedGame {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| RpsError::InvalidLog(reason);
+        let number = |key: &str, value: &str| {
+            value
+                .parse::<u8>()
+                .map_err(|_| invalid(format!("invalid {} '{}'", key, value)))
+        };
+
+        let (mut best_of, mut human_points, mut computer_points) = (None, None, None);
+        let (mut draws, mut winner, mut rounds) = (None, None, None);
+        let mut choice_counts = HashMap::new();
+        for field in s.split_whitespace() {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected key=value, got '{}'", field)))?;
+            match key {
+                "best_of" => best_of = Some(number(key, value)?),
+                "human" => human_points = Some(number(key, value)?),
+                "computer" => computer_points = Some(number(key, value)?),
+                "draws" => draws = Some(number(key, value)?),
+                "rounds" => rounds = Some(number(key, value)?),
+                "winner" => winner = Some(value.parse()?),
+                "rock" | "paper" | "scissors" => {
+                    let count = value
+                        .parse()
+                        .map_err(|_| invalid(format!("invalid {} '{}'", key, value)))?;
+                    choice_counts.insert(key.parse()?, count);
+                }
+                _ => {}
+            }
+        }
+
+        let missing = |key: &str| invalid(format!("missing {}", key));
+        Ok(LoggedGame {
+            best_of: best_of.ok_or_else(|| missing("best_of"))?,
+            human_points: human_points.ok_or_else(|| missing("human"))?,
+            computer_points: computer_points.ok_or_else(|| missing("computer"))?,
+            draws: draws.ok_or_else(|| missing("draws"))?,
+            winner: winner.ok_or_else(|| missing("winner"))?,
+            rounds: rounds.ok_or_else(|| missing("rounds"))?,
+            choice_counts,
+        })
+    }
+}
+
+/// All-time results of the human player across the games of a log.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LifetimeStats {
+    games: ScoreTally,
+    choice_counts: HashMap<Choice, u32>,
+    best_streak: u32,
+}
+
+impl LifetimeStats {
+    /// Reads a game log with one [`LoggedGame`] per line, skipping blank lines.
+    pub fn from_log(log: impl BufRead) -> Result<Self, RpsError> {
+        let mut stats = Self::default();
+        let mut streak = 0;
+        for (index, line) in log.lines().enumerate() {
+            let line = line.map_err(|error| RpsError::InvalidLog(error.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let game = line
+                .parse::<LoggedGame>()
+                .map_err(|error| RpsError::InvalidLog(format!("line {}: {}", index + 1, error)))?;
+
+            stats.games.extend(std::iter::once(game.winner()));
+            streak = match game.winner() {
+                Winner::Human => streak + 1,
+                _ => 0,
+            };
+            stats.best_streak = stats.best_streak.max(streak);
+            for (choice, count) in game.choice_counts() {
+                *stats.choice_counts.entry(*choice).or_insert(0) += count;
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Winners of all logged games.
+    pub fn games(&self) -> ScoreTally {
+        self.games
+    }
+
+    /// Share of the logged games won by the human.
+    pub fn win_rate(&self) -> f64 {
+        match self.games.total() {
+            0 => 0.0,
+            games => f64::from(self.games.human) / f64::from(games),
+        }
+    }
+
+    /// Choice the human played most across all logged games.
+    pub fn favorite_choice(&self) -> Option<Choice> {
+        [Choice::Rock, Choice::Paper, Choice::Scissors]
+            .iter()
+            .copied()
+            .filter(|choice| self.choice_counts.contains_key(choice))
+            .max_by_key(|choice| self.choice_counts[choice])
+    }
+
+    /// Most games won by the human in a row.
+    pub fn best_streak(&self) -> u32 {
+        self.best_streak
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_summary_line() {
+        let game: LoggedGame =
+            "time=1700000000 best_of=5 human=3 computer=1 draws=0 winner=human rounds=4"
+                .parse()
+                .unwrap();
+        assert_eq!(game.winner(), Winner::Human);
+        assert_eq!(game.human_points(), 3);
+        assert_eq!(game.rounds(), 4);
+        assert!(game.choice_counts().is_empty());
+
+        assert_eq!(
+            "best_of=5 human=3".parse::<LoggedGame>(),
+            Err(RpsError::InvalidLog("missing computer".to_string()))
+        );
+    }
+
+    #[test]
+    fn aggregates_fixture_log() {
+        let log = include_str!("../tests/fixtures/games.log");
+        let stats = LifetimeStats::from_log(log.as_bytes()).unwrap();
+
+        assert_eq!(
+            stats.games(),
+            ScoreTally {
+                human: 3,
+                computer: 1,
+                draws: 1
+            }
+        );
+        assert_eq!(stats.win_rate(), 0.6);
+        assert_eq!(stats.favorite_choice(), Some(Choice::Paper));
+        assert_eq!(stats.best_streak(), 2);
+    }
+}
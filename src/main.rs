@@ -1,6 +1,11 @@
-use prettytable::{cell, row, Table};
-use std::convert::TryFrom;
-use std::io;
+use prettytable::{cell, row, Cell, Row, Table};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rps::render::{Renderer, ScoreRow};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use structopt::StructOpt;
 
 extern crate rock_paper_scissors as rps;
@@ -11,68 +16,1532 @@ extern crate rock_paper_scissors as rps;
     about = "Simple rock-paper-scissors game with nice output formatting"
 )]
 struct Opt {
-    /// Number of rounds to be played. Number must be odd and greater than 2.
+    /// Number of rounds to be played. Number must be odd, greater than 2 and at most 99.
+    /// "ft3" or "first-to-3" is short for the best of 5 needed to win 3 rounds.
+    /// Falls back to the RPS_BEST_OF environment variable when not given.
     #[structopt(short = "r", long = "rounds")]
     best_of: Option<rps::BestOf>,
+
+    /// Print only a single-line `key=value` summary of the game, same as `--format oneline`
+    #[structopt(long)]
+    oneline: bool,
+
+    /// Output format of the game: 'table', 'oneline', 'stream', 'csv' or 'json'.
+    /// Formats other than 'table' print nothing else.
+    #[structopt(
+        long,
+        default_value = "table",
+        possible_values = &["table", "oneline", "stream", "csv", "json"]
+    )]
+    format: String,
+
+    /// Computer strategy, see --list-strategies
+    #[structopt(short = "d", long, default_value = "random")]
+    difficulty: String,
+
+    /// Print the available computer strategies and exit
+    #[structopt(long)]
+    list_strategies: bool,
+
+    /// Let the computer explain each of its moves
+    #[structopt(long)]
+    explain: bool,
+
+    /// Print a commentary line after each round
+    #[structopt(long)]
+    commentary: bool,
+
+    /// Reveal the computer's move, locked in before yours, as a separate step
+    #[structopt(long)]
+    reveal: bool,
+
+    /// Pause this many milliseconds before showing the result of each round
+    #[structopt(long)]
+    suspense: Option<u64>,
+
+    /// Vary the --suspense pause randomly by up to this many milliseconds either way.
+    /// Replays always pause exactly --suspense, so they play back the same every time.
+    #[structopt(long, requires = "suspense")]
+    suspense_jitter: Option<u64>,
+
+    /// Seed for the computer's choices and the --suspense-jitter pauses, so a
+    /// game with the same moves plays out the same every time
+    #[structopt(long)]
+    seed: Option<u64>,
+
+    /// Count "Rock... Paper... Scissors... Shoot!" before showing each typed
+    /// move, pausing --suspense milliseconds (400 by default) between words
+    #[structopt(long)]
+    shoot: bool,
+
+    /// Speed multiplier for pauses while replaying a game: 2 is twice as
+    /// fast, 0 shows every round at once
+    #[structopt(long, default_value = "1")]
+    replay_speed: f64,
+
+    /// Comma separated human moves to play instead of reading them from stdin,
+    /// e.g. 'rock,scissors,paper'
+    #[structopt(long)]
+    moves: Option<String>,
+
+    /// Time budget for the whole match in seconds; when it runs out the player
+    /// with more points wins
+    #[structopt(long)]
+    time_budget: Option<u64>,
+
+    /// Chaos mode: normal rules on odd rounds, inverted rules on even rounds
+    #[structopt(long)]
+    chaos: bool,
+
+    /// Always exit with code 0 instead of reporting the winner in the exit code
+    #[structopt(long)]
+    exit_zero: bool,
+
+    /// Save the finished game to this file
+    #[structopt(long)]
+    save: Option<String>,
+
+    /// Append the summary line of the finished game, with a timestamp, to this file
+    #[structopt(long)]
+    log_file: Option<String>,
+
+    /// Print all-time statistics from a file written with --log-file and exit
+    #[structopt(long)]
+    history_stats: Option<String>,
+
+    /// Replay a game saved with --save instead of playing a new one
+    #[structopt(long)]
+    replay: Option<String>,
+
+    /// Continue playing a game saved with --save. Its best of rounds are used
+    /// instead of --rounds.
+    #[structopt(long, conflicts_with = "replay")]
+    resume: Option<String>,
+
+    /// Keep playing until 'quit' or end of input, ignoring the best of rounds
+    #[structopt(long)]
+    endless: bool,
+
+    /// Stop after this many rounds whatever the score, e.g. to cap --endless
+    #[structopt(long)]
+    max_rounds: Option<u8>,
+
+    /// Play all best of rounds even after someone has won the game
+    #[structopt(long)]
+    play_all: bool,
+
+    /// Name of the computer opponent shown in the results
+    #[structopt(long, default_value = "Computer")]
+    opponent_name: String,
+
+    /// Print only the final result, without the banner and round details
+    #[structopt(short, long)]
+    quiet: bool,
+
+    /// Print more while playing: -v adds the running score after each round,
+    /// -vv the computer's rationale and -vvv debug output on stderr
+    #[structopt(short, parse(from_occurrences))]
+    verbose: u8,
+
+    /// Also accept the words for the choices in this language, e.g. 'pl' for
+    /// 'kamień', 'papier' and 'nożyce'. Defaults to the language of the LANG
+    /// environment variable; English words are always accepted.
+    #[structopt(long)]
+    lang: Option<rps::Language>,
+
+    /// Disable colored output
+    #[structopt(long, overrides_with = "color")]
+    no_color: bool,
+
+    /// Enable colored output even when the config file disables it
+    #[structopt(long, overrides_with = "no-color")]
+    color: bool,
+
+    /// Show how the score lead moved over the rounds as a sparkline after the game
+    #[structopt(long)]
+    sparkline: bool,
+
+    /// Show emoji for the players in the totals and winner rows
+    #[structopt(long, overrides_with = "no-emoji")]
+    emoji: bool,
+
+    /// Hide emoji even when the config file enables them
+    #[structopt(long, overrides_with = "emoji")]
+    no_emoji: bool,
+
+    /// Winning margin called a blowout in the results, by default the points
+    /// needed to win
+    #[structopt(long)]
+    blowout_margin: Option<u8>,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let opt = Opt::from_args();
+impl Opt {
+    /// How the human's moves are parsed: with the --lang language, or the one
+    /// of the LANG locale when not given.
+    fn parse_options(&self) -> rps::ParseOptions {
+        let language = self.lang.unwrap_or_else(|| {
+            rps::Language::from_locale(&std::env::var("LANG").unwrap_or_default())
+        });
+        rps::ParseOptions::default().with_language(language)
+    }
 
-    println!();
+    /// Options from the parsed command line, taking every option not given
+    /// there (or, for the rounds, in the RPS_BEST_OF variable looked up with
+    /// `env`) from `config`.
+    fn with_config(
+        matches: &structopt::clap::ArgMatches,
+        config: &rps::Config,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Opt, rps::RpsError> {
+        let mut opt = Opt::from_clap(matches);
+        if opt.best_of.is_none() {
+            opt.best_of = match env("RPS_BEST_OF") {
+                Some(value) => Some(value.parse()?),
+                None => config.best_of,
+            };
+        }
+        if matches.occurrences_of("difficulty") == 0 {
+            if let Some(difficulty) = &config.difficulty {
+                opt.difficulty = difficulty.clone();
+            }
+        }
+        // The later of a flag pair wins, and the config only applies when
+        // neither of them was given.
+        if !opt.color && !opt.no_color {
+            opt.no_color = config.color == Some(false);
+        }
+        if !opt.emoji && !opt.no_emoji {
+            opt.emoji = config.emoji == Some(true);
+        }
+        Ok(opt)
+    }
+}
 
-    let mut game = rps::Game::new(opt.best_of);
+/// How much the CLI prints besides the final result.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Verbosity {
+    /// Only the final result.
+    Quiet,
+    /// Welcome banner and one line per round.
+    Normal,
+    /// Also the running score after each round.
+    Detailed,
+    /// Also the computer's rationale, as with `--explain`.
+    Explain,
+    /// Also debug output on stderr.
+    Debug,
+}
 
-    let mut table = Table::new();
+impl Verbosity {
+    /// Level for the number of `-v` flags, capped at [`Verbosity::Debug`].
+    fn from_occurrences(occurrences: u8) -> Self {
+        match occurrences {
+            0 => Verbosity::Normal,
+            1 => Verbosity::Detailed,
+            2 => Verbosity::Explain,
+            _ => Verbosity::Debug,
+        }
+    }
+}
 
-    println!("Welcome to the ROCK - PAPER - SCISSORS game");
-    println!("Type 'Scissors(s)', 'Rock(r)' or 'Paper(p)' to select your option");
-    println!("Playing best of {} rounds", game.best_of());
-    println!();
+impl Opt {
+    fn verbosity(&self) -> Verbosity {
+        if self.quiet || self.oneline || self.format != "table" {
+            Verbosity::Quiet
+        } else {
+            Verbosity::from_occurrences(self.verbose)
+        }
+    }
 
-    for _ in 0..game.best_of() {
-        let mut human_choice = String::new();
-        io::stdin().read_line(&mut human_choice)?;
-        let human_choice = rps::Choice::try_from(human_choice)? as rps::Choice;
+    /// Renderer for the selected output format, writing to stdout.
+    fn renderer(&self) -> Box<dyn Renderer> {
+        match (self.oneline, self.format.as_str()) {
+            (true, _) | (_, "oneline") => Box::new(rps::render::OnelineRenderer::new(io::stdout())),
+            (_, "stream") => Box::new(rps::render::StreamRenderer::new(io::stdout())),
+            (_, "csv") => Box::new(rps::render::CsvRenderer::new(io::stdout())),
+            (_, "json") => Box::new(rps::render::JsonRenderer::new(io::stdout())),
+            _ => Box::new(TableRenderer {
+                opponent_name: self.opponent_name.clone(),
+                emoji: self.emoji,
+                color: !self.no_color,
+                sparkline: self.sparkline,
+                blowout_margin: self.blowout_margin,
+            }),
+        }
+    }
+}
 
-        let computer_choice: rps::Choice = rand::random();
+/// Prints the results table and the choice histogram to stdout.
+struct TableRenderer {
+    opponent_name: String,
+    emoji: bool,
+    color: bool,
+    sparkline: bool,
+    blowout_margin: Option<u8>,
+}
 
+impl Renderer for TableRenderer {
+    fn finish(&mut self, game: &rps::Game) -> io::Result<()> {
+        println!();
+        results_table(game, &self.opponent_name, self.emoji, self.color).printstd();
+        let blowout_margin = self
+            .blowout_margin
+            .unwrap_or_else(|| game.winning_threshold());
+        if let Some(label) = rps::render::margin_label(game.margin(), blowout_margin) {
+            println!("{}", label);
+        }
+        if game.was_comeback() {
+            println!("What a comeback!");
+        }
+        match game.is_sweep() {
+            Some(rps::Winner::Human) => println!("Perfect game! You won every round."),
+            Some(rps::Winner::Computer) => println!("Swept! The computer won every round."),
+            _ => {}
+        }
+        if self.sparkline {
+            println!(
+                "Lead: {}",
+                rps::render::sparkline(&rps::render::leads(game))
+            );
+        }
+        println!();
+        let stats = game.stats();
+        println!("{}", rps::render::histogram(&stats.choice_counts()));
         println!(
-            "{}. Your choice: {}, Computer choice: {}",
-            game.round(),
-            human_choice,
-            computer_choice
+            "Your randomness: {:.2} / {:.2} bits",
+            stats.human_entropy(),
+            rps::GameStats::max_entropy()
         );
+        Ok(())
+    }
+}
+
+/// Maps the game result to the process exit code: 0 when the human wins,
+/// 1 when the computer wins and 2 on a draw. With `exit_zero` it is always 0.
+/// Errors are still reported by `main` returning `Err`.
+fn exit_code(winner: rps::Winner, exit_zero: bool) -> i32 {
+    match (exit_zero, winner) {
+        (true, _) => 0,
+        (false, rps::Winner::Human) => 0,
+        (false, rps::Winner::Computer) => 1,
+        (false, rps::Winner::Draw) => 2,
+    }
+}
+
+/// Source of the human's moves for [`play`].
+trait MoveReader {
+    /// The next move, `None` when the moves run out or the human quits.
+    /// Input that isn't a move is an [`io::ErrorKind::InvalidData`] error
+    /// wrapping the [`rps::RpsError`], after which the move can be read again.
+    fn read_move(&mut self) -> io::Result<Option<rps::Choice>>;
+}
+
+/// Reads moves typed one per line, e.g. on stdin.
+struct LineReader<R> {
+    input: R,
+    options: rps::ParseOptions,
+}
 
-        let winner = game.round_winner(&human_choice, &computer_choice);
-        
-        let round_row = match winner {
-            rps::Winner::Human => {
-                row![c -> format!("{}", game.round()), BgFdc -> human_choice, BrFdc -> computer_choice]
+impl<R: BufRead> LineReader<R> {
+    fn new(input: R, options: rps::ParseOptions) -> Self {
+        Self { input, options }
+    }
+}
+
+impl<R: BufRead> MoveReader for LineReader<R> {
+    /// Reads exactly one non-blank line per call, so moves typed ahead or
+    /// pasted at once stay buffered and are consumed one per round.
+    fn read_move(&mut self) -> io::Result<Option<rps::Choice>> {
+        let mut human_choice = String::new();
+        loop {
+            human_choice.clear();
+            if self.input.read_line(&mut human_choice)? == 0 {
+                return Ok(None);
             }
-            rps::Winner::Computer => {
-                row![c -> format!("{}", game.round()), BrFdc -> human_choice, BgFdc -> computer_choice]
+            if !human_choice.trim().is_empty() {
+                break;
             }
-            rps::Winner::Draw => {
-                row![c -> format!("{}", game.round()), ByFdc -> human_choice, ByFdc -> computer_choice]
+        }
+        if human_choice.trim().eq_ignore_ascii_case("quit") {
+            return Ok(None);
+        }
+        rps::Choice::parse_with_options(&human_choice, self.options)
+            .map(Some)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = match rps::Config::default_path() {
+        Some(path) => rps::Config::load(&path).unwrap_or_else(|error| {
+            eprintln!(
+                "Warning: ignoring config file {}: {}",
+                path.display(),
+                error
+            );
+            rps::Config::default()
+        }),
+        None => rps::Config::default(),
+    };
+    let opt = Opt::with_config(&Opt::clap().get_matches(), &config, |name| {
+        std::env::var(name).ok()
+    })?;
+
+    if opt.list_strategies {
+        for line in strategy_lines() {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    if let Some(path) = &opt.history_stats {
+        let stats = rps::LifetimeStats::from_log(BufReader::new(File::open(path)?))?;
+        for line in lifetime_lines(&stats) {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    let mut renderer = opt.renderer();
+    let game = match &opt.replay {
+        Some(path) => {
+            let game = rps::Game::load(BufReader::new(File::open(path)?))?;
+            if opt.verbosity() >= Verbosity::Normal {
+                println!();
+                for line in replay_lines(&game, !opt.no_color) {
+                    if let Some(suspense) = opt.suspense {
+                        let delay = scaled_delay(Duration::from_millis(suspense), opt.replay_speed);
+                        if delay > Duration::ZERO {
+                            thread::sleep(delay);
+                        }
+                    }
+                    println!("{}", line);
+                }
+            }
+            game
+        }
+        None => {
+            let mut game = new_game(&opt)?;
+            play(
+                &opt,
+                &mut game,
+                &mut LineReader::new(io::stdin().lock(), opt.parse_options()),
+                &mut io::stdout(),
+                renderer.as_mut(),
+                &rps::SystemClock,
+            )?;
+            game
+        }
+    };
+
+    if let Some(path) = &opt.save {
+        game.save(File::create(path)?)?;
+    }
+
+    if let Some(path) = &opt.log_file {
+        if let Err(error) = append_log(path, &game) {
+            eprintln!("Warning: could not write to log file {}: {}", path, error);
+        }
+    }
+
+    renderer.finish(&game)?;
+
+    io::stdout().flush()?;
+    std::process::exit(exit_code(game.game_winner(), opt.exit_zero))
+}
+
+/// Appends `time=<unix seconds>`, the game's summary line and the human's
+/// choice counts to `path`, in the format read by [`rps::LoggedGame`].
+fn append_log(path: &str, game: &rps::Game) -> io::Result<()> {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    let counts = game.stats().choice_counts();
+    let count = |choice| counts.get(&choice).copied().unwrap_or(0);
+    let mut log = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(
+        log,
+        "time={} {} rock={} paper={} scissors={}",
+        time,
+        game.summary_line(),
+        count(rps::Choice::Rock),
+        count(rps::Choice::Paper),
+        count(rps::Choice::Scissors)
+    )
+}
+
+/// One line per built-in strategy with its name and description.
+fn strategy_lines() -> Vec<String> {
+    rps::strategies()
+        .iter()
+        .map(|info| format!("{:<12} {}", info.name, info.description))
+        .collect()
+}
+
+/// Human readable report of all-time statistics.
+fn lifetime_lines(stats: &rps::LifetimeStats) -> Vec<String> {
+    let games = stats.games();
+    vec![
+        format!(
+            "Games: {} ({} won, {} lost, {} drawn)",
+            games.total(),
+            games.human,
+            games.computer,
+            games.draws
+        ),
+        format!("Win rate: {:.0}%", stats.win_rate() * 100.0),
+        match stats.favorite_choice() {
+            Some(choice) => format!("Favorite gesture: {}", choice),
+            None => "Favorite gesture: none".to_string(),
+        },
+        format!(
+            "Best streak: {}",
+            rps::render::plural(stats.best_streak(), "win", "wins")
+        ),
+    ]
+}
+
+/// Round lines of a saved game, exactly as they were printed while playing it.
+fn replay_lines(game: &rps::Game, color: bool) -> Vec<String> {
+    game.history()
+        .iter()
+        .map(|record| rps::render::round_line(record.round(), record.outcome(), color))
+        .collect()
+}
+
+/// Table with a header, one row per round of the game history, the totals
+/// and the winner. With `emoji` the totals and winner are prefixed with
+/// [`rps::Winner::as_emoji`]; with `color` the totals are colored by
+/// [`total_styles`].
+fn results_table(game: &rps::Game, opponent_name: &str, emoji: bool, color: bool) -> Table {
+    let themed = |winner: rps::Winner, text: String| {
+        if emoji {
+            format!("{} {}", winner.as_emoji(), text)
+        } else {
+            text
+        }
+    };
+    let mut table = Table::new();
+    table.add_row(row![c => "Round", "Player", opponent_name]);
+    for score_row in game.as_scoreboard_rows() {
+        table.add_row(match score_row {
+            ScoreRow::Round {
+                round,
+                human,
+                computer,
+                winner: rps::Winner::Human,
+            } => row![c -> round, BgFdc -> human, BrFdc -> computer],
+            ScoreRow::Round {
+                round,
+                human,
+                computer,
+                winner: rps::Winner::Computer,
+            } => row![c -> round, BrFdc -> human, BgFdc -> computer],
+            ScoreRow::Round {
+                round,
+                human,
+                computer,
+                winner: rps::Winner::Draw,
+            } => row![c -> round, ByFdc -> human, ByFdc -> computer],
+            ScoreRow::Total {
+                human_points,
+                computer_points,
+                leader,
+            } => {
+                let (human_style, computer_style) = total_styles(leader, color);
+                Row::new(vec![
+                    Cell::new("Total").style_spec("c"),
+                    Cell::new(&themed(rps::Winner::Human, human_points.to_string()))
+                        .style_spec(human_style),
+                    Cell::new(&themed(rps::Winner::Computer, computer_points.to_string()))
+                        .style_spec(computer_style),
+                ])
+            }
+            ScoreRow::Winner(winner) => {
+                let winner_text = themed(winner, winner.named(opponent_name).to_string());
+                row![H1c -> "Winner", H2cb -> winner_text]
+            }
+        });
+    }
+    table
+}
+
+/// Style specs of the human's and the computer's total: green for the
+/// leader, red for the trailing player and yellow for both when level.
+fn total_styles(leader: rps::Winner, color: bool) -> (&'static str, &'static str) {
+    match (color, leader) {
+        (false, _) => ("c", "c"),
+        (true, rps::Winner::Human) => ("Fgc", "Frc"),
+        (true, rps::Winner::Computer) => ("Frc", "Fgc"),
+        (true, rps::Winner::Draw) => ("Fyc", "Fyc"),
+    }
+}
+
+/// Pause of `base_ms` milliseconds moved randomly by up to `jitter_ms` in
+/// either direction, never below zero.
+fn suspense_delay(base_ms: u64, jitter_ms: u64, rng: &mut impl rand::Rng) -> Duration {
+    let offset = rng.gen_range(0..=2 * jitter_ms);
+    Duration::from_millis((base_ms + offset).saturating_sub(jitter_ms))
+}
+
+/// Pause between the words of the --shoot count when --suspense is not given.
+const SHOOT_BEAT_MS: u64 = 400;
+
+/// A word of the "rock, paper, scissors, shoot!" count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Beat {
+    Rock,
+    Paper,
+    Scissors,
+    Shoot,
+}
+
+impl Beat {
+    fn next(self) -> Option<Beat> {
+        match self {
+            Beat::Rock => Some(Beat::Paper),
+            Beat::Paper => Some(Beat::Scissors),
+            Beat::Scissors => Some(Beat::Shoot),
+            Beat::Shoot => None,
+        }
+    }
+
+    fn word(self) -> &'static str {
+        match self {
+            Beat::Rock => "Rock...",
+            Beat::Paper => "Paper...",
+            Beat::Scissors => "Scissors...",
+            Beat::Shoot => "Shoot!",
+        }
+    }
+}
+
+/// Prints the count a word per line, waiting `pause()` after every word but
+/// the last.
+fn shoot_cadence(output: &mut impl Write, mut pause: impl FnMut() -> Duration) -> io::Result<()> {
+    let mut beat = Some(Beat::Rock);
+    while let Some(current) = beat {
+        writeln!(output, "{}", current.word())?;
+        output.flush()?;
+        beat = current.next();
+        if beat.is_some() {
+            let delay = pause();
+            if delay > Duration::ZERO {
+                thread::sleep(delay);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `delay` divided by the replay `speed`. A speed of zero or less means no
+/// pause at all, and a pause too long for a `Duration` is the longest one.
+fn scaled_delay(delay: Duration, speed: f64) -> Duration {
+    if speed > 0.0 {
+        Duration::try_from_secs_f64(delay.as_secs_f64() / speed).unwrap_or(Duration::MAX)
+    } else {
+        Duration::ZERO
+    }
+}
+
+/// Fresh game, or the game saved at `--resume`, configured by the command
+/// line options. Options that leave no round to play, like `--max-rounds 0`,
+/// are an error.
+fn new_game(opt: &Opt) -> Result<rps::Game, Box<dyn std::error::Error>> {
+    let game = match &opt.resume {
+        Some(path) => rps::Game::load(BufReader::new(File::open(path)?))?,
+        None => rps::Game::new(opt.best_of),
+    };
+    let mut game = game.with_stop_early(!opt.play_all);
+    if opt.chaos {
+        game = game.with_rule_schedule(rps::AlternatingRules);
+    }
+    if opt.endless {
+        game = game.with_win_condition(rps::WinCondition::Endless);
+    }
+    if let Some(max_rounds) = opt.max_rounds {
+        game = game.try_with_max_rounds(max_rounds)?;
+    }
+    Ok(game)
+}
+
+/// Plays the game until it ends or the human runs out of moves.
+fn play(
+    opt: &Opt,
+    game: &mut rps::Game,
+    input: &mut dyn MoveReader,
+    output: &mut impl Write,
+    renderer: &mut dyn Renderer,
+    clock: &dyn rps::Clock,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut moves = match &opt.moves {
+        Some(list) => {
+            let moves = rps::Choice::parse_many_with_options(list, ',', opt.parse_options())?;
+            Some(moves.into_iter())
+        }
+        None => None,
+    };
+    let mut strategy = rps::strategy_by_name(&opt.difficulty)?;
+    // A resumed game's computer learns from the rounds already played. Its
+    // random choices come from a fresh RNG, so no RNG state is kept.
+    for record in game.history() {
+        strategy.notify(record.outcome());
+    }
+    // Pauses and commentary draw from their own RNG, so turning them on or
+    // off doesn't change the computer's choices.
+    let (mut rng, mut pacing_rng) = match opt.seed {
+        Some(seed) => (
+            StdRng::seed_from_u64(seed),
+            StdRng::seed_from_u64(seed.wrapping_add(1)),
+        ),
+        None => (StdRng::from_entropy(), StdRng::from_entropy()),
+    };
+    let verbosity = opt.verbosity();
+    let chatty = verbosity >= Verbosity::Normal;
+    let explain = opt.explain || verbosity >= Verbosity::Explain;
+    let tally = opt.endless || verbosity >= Verbosity::Detailed;
+
+    if chatty {
+        writeln!(output)?;
+        writeln!(output, "Welcome to the ROCK - PAPER - SCISSORS game")?;
+        writeln!(
+            output,
+            "Type 'Rock(r/1)', 'Paper(p/2)' or 'Scissors(s/3)' to select your option"
+        )?;
+        if opt.endless {
+            writeln!(output, "Playing until you type 'quit'")?;
+        } else {
+            writeln!(
+                output,
+                "Playing best of {}",
+                rps::render::plural(game.best_of(), "round", "rounds")
+            )?;
+        }
+        if !game.history().is_empty() {
+            writeln!(
+                output,
+                "Resuming at round {}, You {} - {} Computer",
+                game.round_number(),
+                game.human_points(),
+                game.computer_points()
+            )?;
+        }
+        writeln!(output)?;
+    }
+
+    let started = clock.now();
+    let mut pending = None;
+
+    while !game.is_over() {
+        if let Some(budget) = opt.time_budget {
+            if clock.elapsed_since(started) >= Duration::from_secs(budget) {
+                if chatty {
+                    match game.resolve_on_time() {
+                        rps::Winner::Draw => writeln!(output, "Time is up! The match is a draw")?,
+                        winner => writeln!(output, "Time is up! {} wins on time", winner)?,
+                    }
+                }
+                break;
             }
+        }
+
+        if opt.chaos && chatty {
+            writeln!(output, "{} this round", game.active_rule())?;
+        }
+
+        // The computer commits before the human's move is read, and keeps its
+        // choice when the human has to type the move again.
+        let committed = pending
+            .take()
+            .unwrap_or_else(|| rps::CommittedChoice::new(strategy.as_mut(), &mut rng));
+        if opt.reveal && chatty {
+            writeln!(output, "The computer has locked in its move.")?;
+        }
+
+        let human_choice = match moves.as_mut() {
+            Some(moves) => match moves.next() {
+                Some(choice) => choice,
+                None => break,
+            },
+            None => match input.read_move() {
+                Ok(Some(choice)) => choice,
+                Ok(None) => break,
+                Err(error) if error.kind() == io::ErrorKind::InvalidData => {
+                    writeln!(output, "{}", error)?;
+                    pending = Some(committed);
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            },
         };
 
-        game.add_point(&winner);
-        table.add_row(round_row);
-        game.round().increase();
+        // Scripted moves skip the count; the computer's move is already locked
+        // in above, so the pauses cannot influence it.
+        let shoot = opt.shoot && chatty && moves.is_none();
+        if shoot {
+            let beat_ms = opt.suspense.unwrap_or(SHOOT_BEAT_MS);
+            let jitter = opt.suspense_jitter.unwrap_or(0);
+            shoot_cadence(output, || suspense_delay(beat_ms, jitter, &mut pacing_rng))?;
+        }
+
+        let round_number = game.round_number();
+        let outcome = match committed.reveal(game, human_choice) {
+            rps::GamePhase::RoundResolved(outcome) => outcome,
+            _ => break,
+        };
+        if let (Some(suspense), true, false) = (opt.suspense, chatty, shoot) {
+            output.flush()?;
+            thread::sleep(suspense_delay(
+                suspense,
+                opt.suspense_jitter.unwrap_or(0),
+                &mut pacing_rng,
+            ));
+        }
+        if opt.reveal && chatty {
+            writeln!(output, "The computer reveals {:#}!", outcome.computer())?;
+        }
+        strategy.notify(&outcome);
+        renderer.round(round_number, &outcome)?;
+        if verbosity >= Verbosity::Debug {
+            eprintln!(
+                "debug: round={} {:?} rounds_played={} over={}",
+                round_number,
+                outcome,
+                game.rounds_played(),
+                game.is_over()
+            );
+        }
 
-        if game.enough_points_to_end_game() {
-            break
+        if chatty {
+            writeln!(
+                output,
+                "{}",
+                rps::render::round_line(round_number, &outcome, !opt.no_color)
+            )?;
+            if explain {
+                if let Some(rationale) = strategy.rationale() {
+                    writeln!(output, "   Computer: {}", rationale)?;
+                }
+            }
+            if opt.commentary {
+                writeln!(
+                    output,
+                    "   {}",
+                    rps::render::commentary(&outcome, &mut pacing_rng)
+                )?;
+            }
+            if tally {
+                writeln!(
+                    output,
+                    "   Tally: You {} - {} Computer, {}",
+                    game.human_points(),
+                    game.computer_points(),
+                    rps::render::plural(game.draws(), "draw", "draws")
+                )?;
+            }
         }
     }
 
-    println!();
-    table.insert_row(0, row![c => "Round", "Player", "Computer"]);
-    table.add_row(row![c => "Total", game.human_points(), game.computer_points()]);
-    table.add_row(row![H1c -> "Winner", H2cb -> format!("{}", game.game_winner())]);
-    table.printstd();
+    if game.is_at_round_limit() && chatty {
+        writeln!(
+            output,
+            "Stopped after {}, the most a game can have",
+            rps::render::plural(game.rounds_played(), "round", "rounds")
+        )?;
+    }
 
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn command_line_overrides_config() {
+        let config: rps::Config = "best_of = 7\ndifficulty = markov\ncolor = false\nemoji = true\n"
+            .parse()
+            .unwrap();
+
+        let opt = |args: &[&str], config: &rps::Config| {
+            Opt::with_config(&Opt::clap().get_matches_from(args), config, |_| None).unwrap()
+        };
+
+        let from_config = opt(&["rps"], &config);
+        assert_eq!(rps::Game::new(from_config.best_of).best_of(), 7);
+        assert_eq!(from_config.difficulty, "markov");
+        assert!(from_config.no_color);
+        assert!(from_config.emoji);
+
+        let args = ["rps", "-r", "3", "-d", "random", "--color", "--no-emoji"];
+        let overridden = opt(&args, &config);
+        assert_eq!(rps::Game::new(overridden.best_of).best_of(), 3);
+        assert_eq!(overridden.difficulty, "random");
+        assert!(!overridden.no_color);
+        assert!(!overridden.emoji);
+
+        let last_wins = opt(
+            &["rps", "--no-emoji", "--emoji", "--color", "--no-color"],
+            &config,
+        );
+        assert!(last_wins.emoji);
+        assert!(last_wins.no_color);
+
+        let defaults = opt(&["rps"], &rps::Config::default());
+        assert_eq!(defaults.difficulty, "random");
+        assert!(!defaults.no_color);
+    }
+
+    #[test]
+    fn best_of_is_read_from_environment() {
+        let config: rps::Config = "best_of = 5\n".parse().unwrap();
+        let opt = |args: &[&str], value: &str| {
+            Opt::with_config(&Opt::clap().get_matches_from(args), &config, |name| {
+                assert_eq!(name, "RPS_BEST_OF");
+                Some(value.to_string())
+            })
+        };
+
+        let from_env = opt(&["rps"], "7").unwrap();
+        assert_eq!(rps::Game::new(from_env.best_of).best_of(), 7);
+        let from_flag = opt(&["rps", "--rounds", "3"], "7").unwrap();
+        assert_eq!(rps::Game::new(from_flag.best_of).best_of(), 3);
+        assert!(opt(&["rps"], "4").is_err());
+    }
+
+    #[test]
+    fn moves_flag_plays_moves_in_order() {
+        let args = ["rps", "-r", "5", "--moves", "rock,scissors,paper"];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut game = rps::Game::new(opt.best_of);
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        let humans: Vec<_> = game
+            .history()
+            .iter()
+            .map(|record| record.outcome().human())
+            .collect();
+        assert_eq!(
+            humans,
+            vec![rps::Choice::Rock, rps::Choice::Scissors, rps::Choice::Paper]
+        );
+    }
+
+    #[test]
+    fn play_all_continues_after_clinch() {
+        let moves = "rock,rock,rock,rock,rock,rock";
+        let args = [
+            "rps",
+            "-r",
+            "5",
+            "-d",
+            "weighted:0,0,1",
+            "--moves",
+            moves,
+            "--play-all",
+        ];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut game = new_game(&opt).unwrap();
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(game.history().len(), 5);
+        assert_eq!(game.human_points(), 5);
+        assert_eq!(game.game_winner(), rps::Winner::Human);
+    }
+
+    #[test]
+    fn lang_flag_accepts_translated_moves() {
+        let args = ["rps", "--lang", "pl", "--moves", "kamień,papier,nożyce"];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut game = rps::Game::new(Some("5".parse().unwrap()));
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        let humans: Vec<_> = game
+            .history()
+            .iter()
+            .map(|record| record.outcome().human())
+            .collect();
+        assert_eq!(
+            humans,
+            vec![rps::Choice::Rock, rps::Choice::Paper, rps::Choice::Scissors]
+        );
+        assert!(Opt::from_iter_safe(&["rps", "--lang", "xx"]).is_err());
+    }
+
+    #[test]
+    fn invalid_move_reports_its_position() {
+        let opt = Opt::from_iter_safe(&["rps", "--moves", "rock,stone,paper"]).unwrap();
+        let error = play(
+            &opt,
+            &mut rps::Game::new(opt.best_of),
+            &mut ScriptedReader::default(),
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Move 2 is invalid: Unknown choice 'stone'"
+        );
+    }
+
+    #[test]
+    fn exit_code_reflects_winner() {
+        assert_eq!(exit_code(rps::Winner::Human, false), 0);
+        assert_eq!(exit_code(rps::Winner::Computer, false), 1);
+        assert_eq!(exit_code(rps::Winner::Draw, false), 2);
+        assert_eq!(exit_code(rps::Winner::Computer, true), 0);
+        assert_eq!(exit_code(rps::Winner::Draw, true), 0);
+    }
+
+    #[test]
+    fn replay_renders_rounds_from_history() {
+        let fixture = include_str!("../tests/fixtures/game.txt");
+        let game = rps::Game::load(fixture.as_bytes()).unwrap();
+
+        assert_eq!(
+            replay_lines(&game, false),
+            vec![
+                "1. Your choice: Rock, Computer choice: Paper",
+                "2. Your choice: Scissors, Computer choice: Paper",
+                "3. Your choice: Paper, Computer choice: Paper",
+                "4. Your choice: Rock, Computer choice: Scissors",
+                "5. Your choice: Rock, Computer choice: Scissors",
+            ]
+        );
+        assert_eq!(game.game_winner(), rps::Winner::Human);
+    }
+
+    #[test]
+    fn buffered_lines_are_played_one_per_round() {
+        let opt = Opt::from_iter_safe(&["rps", "--endless"]).unwrap();
+        let mut game = rps::Game::new(opt.best_of).with_win_condition(rps::WinCondition::Endless);
+        let mut input = LineReader::new("r\r\ns\n\np\n".as_bytes(), opt.parse_options());
+        play(
+            &opt,
+            &mut game,
+            &mut input,
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(game.rounds_played(), 3);
+        let humans: Vec<_> = game.history().iter().map(|r| r.outcome().human()).collect();
+        assert_eq!(
+            humans,
+            vec![rps::Choice::Rock, rps::Choice::Scissors, rps::Choice::Paper]
+        );
+    }
+
+    #[test]
+    fn typo_prints_suggestion_and_asks_again() {
+        let opt = Opt::from_iter_safe(&["rps", "--endless"]).unwrap();
+        let mut game = rps::Game::new(opt.best_of).with_win_condition(rps::WinCondition::Endless);
+        let mut input = LineReader::new("rok\nr\nr\n".as_bytes(), opt.parse_options());
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut game,
+            &mut input,
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(game.rounds_played(), 2);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Unknown choice 'rok', did you mean Rock?"));
+    }
+
+    /// Hands out a fixed list of moves, then ends the input.
+    #[derive(Default)]
+    struct ScriptedReader(VecDeque<Result<rps::Choice, rps::RpsError>>);
+
+    impl MoveReader for ScriptedReader {
+        fn read_move(&mut self) -> io::Result<Option<rps::Choice>> {
+            match self.0.pop_front() {
+                Some(Ok(choice)) => Ok(Some(choice)),
+                Some(Err(error)) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+                None => Ok(None),
+            }
+        }
+    }
+
+    #[test]
+    fn bad_move_is_reported_and_read_again() {
+        let opt = Opt::from_iter_safe(&["rps", "--endless"]).unwrap();
+        let mut game = rps::Game::new(opt.best_of).with_win_condition(rps::WinCondition::Endless);
+        let mut input = ScriptedReader(VecDeque::from(vec![
+            Err(rps::RpsError::UnknownChoice {
+                input: "lizard".to_string(),
+                suggestion: None,
+            }),
+            Ok(rps::Choice::Paper),
+        ]));
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut game,
+            &mut input,
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Unknown choice 'lizard'").count(), 1);
+        assert_eq!(game.history().len(), 1);
+        assert_eq!(game.history()[0].outcome().human(), rps::Choice::Paper);
+    }
+
+    #[test]
+    fn endless_game_stops_at_round_limit() {
+        let opt = Opt::from_iter_safe(&["rps", "-r", "3", "--endless"]).unwrap();
+        let mut game = rps::Game::new(opt.best_of).with_win_condition(rps::WinCondition::Endless);
+        let lines = "r\n".repeat(300);
+        let mut input = LineReader::new(lines.as_bytes(), opt.parse_options());
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut game,
+            &mut input,
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(game.rounds_played(), 254);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Stopped after 254 rounds"));
+    }
+
+    #[test]
+    fn endless_game_ends_on_quit() {
+        let opt = Opt::from_iter_safe(&["rps", "-r", "3", "--endless"]).unwrap();
+        let mut game = rps::Game::new(opt.best_of).with_win_condition(rps::WinCondition::Endless);
+        let mut input = LineReader::new("r\np\ns\nr\nquit\nr\n".as_bytes(), opt.parse_options());
+        play(
+            &opt,
+            &mut game,
+            &mut input,
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(game.history().len(), 4);
+        assert_eq!(
+            game.human_points() + game.computer_points() + game.draws(),
+            4
+        );
+    }
+
+    #[test]
+    fn opponent_name_appears_in_results() {
+        let mut game = rps::Game::new(None);
+        for _ in 0..3 {
+            game.play_round(rps::Choice::Rock, rps::Choice::Paper)
+                .unwrap();
+        }
+        let table = results_table(&game, "HAL", false, true);
+        let cell = |row: usize, column: usize| {
+            let row = table.get_row(row).unwrap();
+            row.get_cell(column).unwrap().get_content()
+        };
+
+        assert_eq!(cell(0, 2), "HAL");
+        assert_eq!(cell(table.len() - 1, 1), "HAL");
+    }
+
+    #[test]
+    fn totals_are_colored_by_leader() {
+        assert_eq!(total_styles(rps::Winner::Human, true), ("Fgc", "Frc"));
+        assert_eq!(total_styles(rps::Winner::Computer, true), ("Frc", "Fgc"));
+        assert_eq!(total_styles(rps::Winner::Draw, true), ("Fyc", "Fyc"));
+        assert_eq!(total_styles(rps::Winner::Human, false), ("c", "c"));
+    }
+
+    #[test]
+    fn emoji_results_prefix_totals_and_winner() {
+        let mut game = rps::Game::new(None);
+        for _ in 0..3 {
+            game.play_round(rps::Choice::Rock, rps::Choice::Scissors)
+                .unwrap();
+        }
+        let table = results_table(&game, "HAL", true, true);
+        let cell = |row: usize, column: usize| {
+            let row = table.get_row(row).unwrap();
+            row.get_cell(column).unwrap().get_content()
+        };
+
+        assert_eq!(cell(table.len() - 2, 1), "🧑 3");
+        assert_eq!(cell(table.len() - 2, 2), "🤖 0");
+        assert_eq!(cell(table.len() - 1, 1), "🧑 Human");
+    }
+
+    #[test]
+    fn resumed_game_continues_where_it_was_saved() {
+        let path = std::env::temp_dir().join(format!("rps-resume-{}.txt", std::process::id()));
+        let mut saved = rps::Game::new(None);
+        saved
+            .play_round(rps::Choice::Rock, rps::Choice::Scissors)
+            .unwrap();
+        saved
+            .play_round(rps::Choice::Rock, rps::Choice::Paper)
+            .unwrap();
+        saved.save(File::create(&path).unwrap()).unwrap();
+
+        let args = [
+            "rps",
+            "-r",
+            "9",
+            "-d",
+            "weighted:0,0,1",
+            "--moves",
+            "rock,rock,rock",
+        ];
+        let opt =
+            Opt::from_iter_safe(args.iter().chain(&["--resume", path.to_str().unwrap()])).unwrap();
+        let mut game = new_game(&opt).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(game, saved);
+        assert_eq!(game.round_number(), 3);
+
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Resuming at round 3, You 1 - 1 Computer"));
+        assert_eq!(game.best_of(), 5);
+        assert_eq!(game.history().len(), 4);
+        assert_eq!((game.human_points(), game.computer_points()), (3, 1));
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn log_file_appends_summary_lines() {
+        let path = std::env::temp_dir().join(format!("rps-log-{}.log", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut game = rps::Game::new(None);
+        for _ in 0..3 {
+            game.play_round(rps::Choice::Rock, rps::Choice::Scissors)
+                .unwrap();
+        }
+        append_log(path, &game).unwrap();
+        append_log(path, &game).unwrap();
+
+        let log = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        let lines: Vec<_> = log.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            assert!(line.starts_with("time="));
+            assert!(line.contains(&game.summary_line()));
+            assert_eq!(
+                line.parse::<rps::LoggedGame>().unwrap().winner(),
+                rps::Winner::Human
+            );
+        }
+    }
+
+    #[test]
+    fn strategy_list_has_a_line_per_strategy() {
+        let lines = strategy_lines();
+        assert_eq!(lines.len(), rps::strategies().len());
+        assert_eq!(lines[0], "random       Picks uniformly at random");
+    }
+
+    #[test]
+    fn history_stats_report_fixture_log() {
+        let log = include_str!("../tests/fixtures/games.log");
+        let stats = rps::LifetimeStats::from_log(log.as_bytes()).unwrap();
+
+        assert_eq!(
+            lifetime_lines(&stats),
+            vec![
+                "Games: 5 (3 won, 1 lost, 1 drawn)",
+                "Win rate: 60%",
+                "Favorite gesture: Paper",
+                "Best streak: 2 wins",
+            ]
+        );
+    }
+
+    fn sink_renderer() -> rps::render::OnelineRenderer<io::Sink> {
+        rps::render::OnelineRenderer::new(io::sink())
+    }
+
+    /// Lets a fixed amount of time pass on the clock after every round.
+    struct SlowRounds(rps::MockClock, Duration);
+
+    impl Renderer for SlowRounds {
+        fn round(&mut self, _round: u8, _outcome: &rps::RoundOutcome) -> io::Result<()> {
+            self.0.advance(self.1);
+            Ok(())
+        }
+
+        fn finish(&mut self, _game: &rps::Game) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn time_budget_ends_the_match_on_time() {
+        let args = [
+            "rps",
+            "-d",
+            "weighted:0,0,1",
+            "--moves",
+            "rock,rock,rock",
+            "--time-budget",
+            "10",
+        ];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let clock = rps::MockClock::new();
+        let mut game = rps::Game::new(opt.best_of);
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut output,
+            &mut SlowRounds(clock.clone(), Duration::from_secs(6)),
+            &clock,
+        )
+        .unwrap();
+
+        assert_eq!(game.history().len(), 2);
+        assert!(!game.is_over());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Time is up! Human wins on time"));
+    }
+
+    #[test]
+    fn stream_format_renders_each_round() {
+        let args = ["rps", "-r", "3", "--format", "stream", "--moves", "r,r,r"];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut game = rps::Game::new(opt.best_of);
+        let mut output = Vec::new();
+        let mut rendered = Vec::new();
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut output,
+            &mut rps::render::StreamRenderer::new(&mut rendered),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        assert!(output.is_empty());
+        let rendered = String::from_utf8(rendered).unwrap();
+        assert_eq!(rendered.lines().count(), game.rounds_played() as usize);
+        assert!(rendered.starts_with("round=1 human=rock "));
+    }
+
+    #[test]
+    fn verbose_flags_map_to_levels() {
+        let verbosity = |args: &[&str]| Opt::from_iter_safe(args).unwrap().verbosity();
+        assert_eq!(verbosity(&["rps"]), Verbosity::Normal);
+        assert_eq!(verbosity(&["rps", "-v"]), Verbosity::Detailed);
+        assert_eq!(verbosity(&["rps", "-vv"]), Verbosity::Explain);
+        assert_eq!(verbosity(&["rps", "-vvv"]), Verbosity::Debug);
+        assert_eq!(verbosity(&["rps", "-vvvvv"]), Verbosity::Debug);
+        assert_eq!(verbosity(&["rps", "-vv", "-q"]), Verbosity::Quiet);
+    }
+
+    #[test]
+    fn suspense_jitter_stays_within_bounds() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let delay = suspense_delay(500, 200, &mut rng);
+            assert!(delay >= Duration::from_millis(300) && delay <= Duration::from_millis(700));
+        }
+        assert!(suspense_delay(50, 200, &mut rng) <= Duration::from_millis(250));
+        assert_eq!(suspense_delay(500, 0, &mut rng), Duration::from_millis(500));
+
+        let delays = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..5)
+                .map(|_| suspense_delay(500, 200, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(delays(3), delays(3));
+    }
+
+    #[test]
+    fn seed_makes_the_computer_repeat_its_moves() {
+        let computers = |seed: &str| computer_moves(&["--seed", seed]);
+
+        assert_eq!(computers("42").len(), 9);
+        assert_eq!(computers("42"), computers("42"));
+        assert_ne!(computers("42"), computers("7"));
+    }
+
+    #[test]
+    fn pauses_and_commentary_leave_the_seeded_moves_unchanged() {
+        let plain = computer_moves(&["--seed", "42"]);
+        let paced = computer_moves(&[
+            "--seed",
+            "42",
+            "--suspense",
+            "1",
+            "--suspense-jitter",
+            "1",
+            "--commentary",
+        ]);
+        assert_eq!(plain, paced);
+    }
+
+    /// Computer's moves in a best of 9 against a fixed list of moves, played
+    /// with the extra `flags`.
+    fn computer_moves(flags: &[&str]) -> Vec<rps::Choice> {
+        let mut args = vec![
+            "rps",
+            "-r",
+            "9",
+            "-d",
+            "random",
+            "--moves",
+            "r,p,s,r,p,s,r,p,s",
+            "--play-all",
+        ];
+        args.extend_from_slice(flags);
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut game = new_game(&opt).unwrap();
+        play(
+            &opt,
+            &mut game,
+            &mut ScriptedReader::default(),
+            &mut io::sink(),
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+        game.history()
+            .iter()
+            .map(|record| record.outcome().computer())
+            .collect()
+    }
+
+    #[test]
+    fn shoot_count_follows_the_locked_in_move() {
+        let args = ["rps", "-r", "3", "--reveal", "--shoot", "--suspense", "0"];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut rps::Game::new(opt.best_of),
+            &mut LineReader::new("r\n".as_bytes(), opt.parse_options()),
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let position = |text: &str| output.find(text).unwrap();
+        assert!(position("locked in") < position("Rock..."));
+        assert!(position("Rock...") < position("Paper..."));
+        assert!(position("Scissors...") < position("Shoot!"));
+        assert!(position("Shoot!") < position("The computer reveals"));
+
+        let args = ["rps", "-r", "3", "--shoot", "--moves", "r"];
+        let opt = Opt::from_iter_safe(&args).unwrap();
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut rps::Game::new(opt.best_of),
+            &mut ScriptedReader::default(),
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("Shoot!"));
+    }
+
+    #[test]
+    fn replay_speed_scales_delay() {
+        let delay = Duration::from_millis(500);
+        assert_eq!(scaled_delay(delay, 2.0), Duration::from_millis(250));
+        assert_eq!(scaled_delay(delay, 1.0), delay);
+        assert_eq!(scaled_delay(delay, 0.5), Duration::from_millis(1000));
+        assert_eq!(scaled_delay(delay, 0.0), Duration::ZERO);
+        assert_eq!(scaled_delay(delay, 1e-300), Duration::MAX);
+        assert_eq!(scaled_delay(delay, f64::NAN), Duration::ZERO);
+    }
+
+    #[test]
+    fn quiet_mode_has_no_banner() {
+        let opt = Opt::from_iter_safe(&["rps", "-r", "3", "-q", "--moves", "r,r,r"]).unwrap();
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut rps::Game::new(opt.best_of),
+            &mut ScriptedReader::default(),
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+        assert!(output.is_empty());
+
+        let opt = Opt::from_iter_safe(&["rps", "-r", "3", "--moves", "r,r,r"]).unwrap();
+        let mut output = Vec::new();
+        play(
+            &opt,
+            &mut rps::Game::new(opt.best_of),
+            &mut ScriptedReader::default(),
+            &mut output,
+            &mut sink_renderer(),
+            &rps::SystemClock,
+        )
+        .unwrap();
+        assert!(String::from_utf8(output).unwrap().contains("Welcome"));
+    }
+}
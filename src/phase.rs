@@ -0,0 +1,169 @@
+#[cfg(feature = "std")]
+use crate::Strategy;
+use crate::{Choice, Game, RoundOutcome, Winner};
+use rand::{Rng, RngCore};
+
+/// Where a game is in its flow, as reported by [`Game::phase`] and
+/// [`Game::step`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamePhase {
+    /// The next round is waiting for the human's choice.
+    AwaitingMove,
+    /// A round was just played.
+    RoundResolved(RoundOutcome),
+    /// The game is over and no more rounds will be played.
+    Finished(Winner),
+}
+
+impl Game {
+    /// `Finished` with the game winner once the game is over, otherwise
+    /// `AwaitingMove`.
+    pub fn phase(&self) -> GamePhase {
+        if self.is_over() {
+            GamePhase::Finished(self.game_winner())
+        } else {
+            GamePhase::AwaitingMove
+        }
+    }
+
+    /// Plays one round of `human` against a random computer choice, see
+    /// [`Game::step_against`].
+    #[cfg(feature = "std")]
+    pub fn step(&mut self, human: Choice) -> GamePhase {
+        self.step_with_rng(human, &mut rand::thread_rng())
+    }
+
+    /// Like [`Game::step`], with the computer's choice and any
+    /// [`DrawResolution::Random`](crate::DrawResolution::Random) coin flip
+    /// drawn from `rng`, so seeded games are reproducible.
+    pub fn step_with_rng(&mut self, human: Choice, rng: &mut dyn RngCore) -> GamePhase {
+        let computer = rng.gen();
+        match self.play_round_with_rng(human, computer, rng) {
+            Ok(outcome) => GamePhase::RoundResolved(outcome),
+            Err(_) => self.phase(),
+        }
+    }
+
+    /// Plays one round and returns `RoundResolved` with its outcome, or
+    /// `Finished` without playing when the game is already over.
+    pub fn step_against(&mut self, human: Choice, computer: Choice) -> GamePhase {
+        match self.play_round(human, computer) {
+            Ok(outcome) => GamePhase::RoundResolved(outcome),
+            Err(_) => self.phase(),
+        }
+    }
+}
+
+/// Computer choice fixed before the human's choice is known. It can't be
+/// read until it is played with [`CommittedChoice::reveal`].
+#[cfg(feature = "std")]
+pub struct CommittedChoice {
+    choice: Choice,
+}
+
+#[cfg(feature = "std")]
+impl CommittedChoice {
+    /// Lets `strategy` decide the computer's choice for the next round.
+    pub fn new(strategy: &mut dyn Strategy, rng: &mut dyn RngCore) -> Self {
+        Self {
+            choice: strategy.choose(rng),
+        }
+    }
+
+    /// Plays the next round of `game` with `human` against the committed
+    /// choice, see [`Game::step_against`].
+    pub fn reveal(self, game: &mut Game, human: Choice) -> GamePhase {
+        game.step_against(human, self.choice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn steps_through_a_full_game() {
+        let mut game = Game::best_of_rounds(3).unwrap();
+        let mut phases = vec![game.phase()];
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Rock),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Paper, Choice::Rock),
+            (Choice::Paper, Choice::Rock),
+        ] {
+            phases.push(game.step_against(*human, *computer));
+        }
+        phases.push(game.phase());
+
+        assert_eq!(
+            phases,
+            vec![
+                GamePhase::AwaitingMove,
+                GamePhase::RoundResolved(RoundOutcome::new(
+                    Choice::Rock,
+                    Choice::Rock,
+                    Winner::Draw
+                )),
+                GamePhase::RoundResolved(RoundOutcome::new(
+                    Choice::Rock,
+                    Choice::Scissors,
+                    Winner::Human
+                )),
+                GamePhase::RoundResolved(RoundOutcome::new(
+                    Choice::Paper,
+                    Choice::Rock,
+                    Winner::Human
+                )),
+                GamePhase::Finished(Winner::Human),
+                GamePhase::Finished(Winner::Human),
+            ]
+        );
+        assert_eq!(game.rounds_played(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn seeded_steps_are_reproducible() {
+        use crate::{BestOf, DrawResolution};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let phases = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut game = Game::new(Some(BestOf::new(9).unwrap()))
+                .with_draw_resolution(DrawResolution::Random);
+            (0..9)
+                .map(|_| game.step_with_rng(Choice::Rock, &mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(phases(11), phases(11));
+        assert!(phases(11).iter().all(|phase| !matches!(
+            phase,
+            GamePhase::RoundResolved(outcome) if outcome.winner() == Winner::Draw
+        )));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn committed_choice_does_not_depend_on_human_choice() {
+        use crate::RandomStrategy;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let computers: Vec<_> = [Choice::Rock, Choice::Paper, Choice::Scissors]
+            .iter()
+            .map(|human| {
+                let mut rng = StdRng::seed_from_u64(7);
+                let committed = CommittedChoice::new(&mut RandomStrategy, &mut rng);
+                match committed.reveal(&mut Game::new(None), *human) {
+                    GamePhase::RoundResolved(outcome) => outcome.computer(),
+                    phase => panic!("unexpected phase {:?}", phase),
+                }
+            })
+            .collect();
+
+        assert!(computers.iter().all(|computer| *computer == computers[0]));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{BestOf, Choice, Game, RpsError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Smallest artifact a game can be reconstructed from: the seed of the
+/// computer's random choices, the best of and the human's moves.
+///
+/// It is written as a single line, e.g. `seed=7 best_of=5 moves=rock,paper,rock`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecording {
+    seed: u64,
+    best_of: BestOf,
+    human_moves: Vec<Choice>,
+}
+
+impl GameRecording {
+    pub fn new(seed: u64, best_of: BestOf, human_moves: Vec<Choice>) -> Self {
+        Self {
+            seed,
+            best_of,
+            human_moves,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn best_of(&self) -> BestOf {
+        self.best_of
+    }
+
+    pub fn human_moves(&self) -> &[Choice] {
+        &self.human_moves
+    }
+}
+
+impl Game {
+    /// Plays the recorded human moves against random computer choices drawn
+    /// from a [`StdRng`] seeded with the recording's seed, stopping once the
+    /// game is over.
+    pub fn from_recording(recording: &GameRecording) -> Game {
+        let mut rng = StdRng::seed_from_u64(recording.seed);
+        let mut game = Game::new(Some(recording.best_of));
+        for human in &recording.human_moves {
+            if game.play_round(*human, rng.gen()).is_err() {
+                break;
+            }
+        }
+        game
+    }
+}
+
+impl Display for GameRecording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let moves = self
+            .human_moves
+            .iter()
+            .map(|choice| choice.to_string().to_lowercase())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "seed={} best_of={} moves={}",
+            self.seed, self.best_of.0, moves
+        )
+    }
+}
+
+impl FromStr for GameRecording {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| RpsError::InvalidSave(reason.to_string());
+        let (mut seed, mut best_of, mut human_moves) = (None, None, None);
+        for field in s.split_whitespace() {
+            match field.split_once('=') {
+                Some(("seed", value)) => {
+                    seed = Some(value.parse().map_err(|_| invalid("invalid seed"))?)
+                }
+                Some(("best_of", value)) => best_of = Some(value.parse()?),
+                Some(("moves", "")) => human_moves = Some(Vec::new()),
+                Some(("moves", value)) => {
+                    human_moves = Some(
+                        value
+                            .split(',')
+                            .map(str::parse)
+                            .collect::<Result<Vec<Choice>, _>>()?,
+                    )
+                }
+                _ => return Err(invalid(&format!("unexpected field '{}'", field))),
+            }
+        }
+        Ok(GameRecording {
+            seed: seed.ok_or_else(|| invalid("missing seed"))?,
+            best_of: best_of.ok_or_else(|| invalid("missing best_of"))?,
+            human_moves: human_moves.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_reconstructs_the_same_game() {
+        let best_of = BestOf::new(5).unwrap();
+        let human_moves = vec![
+            Choice::Rock,
+            Choice::Paper,
+            Choice::Scissors,
+            Choice::Rock,
+            Choice::Paper,
+            Choice::Paper,
+            Choice::Scissors,
+        ];
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut played = Game::new(Some(best_of));
+        for human in &human_moves {
+            if played.is_over() {
+                break;
+            }
+            played.play_round(*human, rng.gen()).unwrap();
+        }
+
+        let recording = GameRecording::new(42, best_of, human_moves);
+        let text = recording.to_string();
+        let restored = Game::from_recording(&text.parse().unwrap());
+
+        assert_eq!(text.parse::<GameRecording>().unwrap(), recording);
+        assert_eq!(restored, played);
+        assert_eq!(restored.history(), played.history());
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!("seed=1 best_of=3 moves=rock extra=1"
+            .parse::<GameRecording>()
+            .is_err());
+        assert!("best_of=3 moves=rock".parse::<GameRecording>().is_err());
+    }
+}
@@ -0,0 +1,498 @@
+use crate::{Choice, Game, RoundOutcome, Winner};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+const MAX_BAR_WIDTH: u32 = 20;
+
+/// Bar chart with one line per choice, e.g. `Rock     ████ 4`.
+///
+/// Every move is one block until the largest count exceeds 20 blocks, after
+/// which bars are scaled down proportionally.
+pub fn histogram(counts: &HashMap<Choice, u32>) -> String {
+    let max = counts.values().copied().max().unwrap_or(0);
+    [Choice::Rock, Choice::Paper, Choice::Scissors]
+        .iter()
+        .map(|choice| {
+            let count = counts.get(choice).copied().unwrap_or(0);
+            let width = if max > MAX_BAR_WIDTH {
+                count * MAX_BAR_WIDTH / max
+            } else {
+                count
+            };
+            format!(
+                "{:<8} {} {}",
+                choice.to_string(),
+                "█".repeat(width as usize),
+                count
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `count` followed by the singular or plural form of a noun, e.g.
+/// `1 round` or `5 rounds`.
+pub fn plural(count: impl Into<u64>, singular: &str, plural: &str) -> String {
+    match count.into() {
+        1 => format!("1 {}", singular),
+        count => format!("{} {}", count, plural),
+    }
+}
+
+/// Label for the final margin: "Blowout!" from `blowout_margin` points up,
+/// "Nail-biter!" for a single point, and nothing otherwise.
+pub fn margin_label(margin: u8, blowout_margin: u8) -> Option<&'static str> {
+    match margin {
+        0 => None,
+        margin if margin >= blowout_margin => Some("Blowout!"),
+        1 => Some("Nail-biter!"),
+        _ => None,
+    }
+}
+
+/// One row of the results scoreboard, without any presentation, see
+/// [`Game::as_scoreboard_rows`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreRow {
+    /// A round of the history.
+    Round {
+        round: u8,
+        human: Choice,
+        computer: Choice,
+        winner: Winner,
+    },
+    /// Points of both players and who is ahead.
+    Total {
+        human_points: u8,
+        computer_points: u8,
+        leader: Winner,
+    },
+    /// Winner of the game.
+    Winner(Winner),
+}
+
+impl Game {
+    /// Scoreboard of the game: one row per round, then the totals and the
+    /// winner.
+    pub fn as_scoreboard_rows(&self) -> Vec<ScoreRow> {
+        let rounds = self.history().iter().map(|record| {
+            let outcome = record.outcome();
+            ScoreRow::Round {
+                round: record.round(),
+                human: outcome.human(),
+                computer: outcome.computer(),
+                winner: outcome.winner(),
+            }
+        });
+        let summary = [
+            ScoreRow::Total {
+                human_points: self.human_points(),
+                computer_points: self.computer_points(),
+                leader: self.leader(),
+            },
+            ScoreRow::Winner(self.game_winner()),
+        ];
+        rounds.chain(summary).collect()
+    }
+}
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One block character per value, the lowest value as the lowest block and
+/// every step up one block higher, capped at the full block, e.g.
+/// `[0, 1, 2, 1]` is `▁▂▃▂`.
+pub fn sparkline(points: &[i8]) -> String {
+    let lowest = points.iter().copied().min().unwrap_or_default();
+    points
+        .iter()
+        .map(|point| {
+            let height = usize::from(point.abs_diff(lowest));
+            SPARKS[height.min(SPARKS.len() - 1)]
+        })
+        .collect()
+}
+
+/// The human's lead in points after every round of the game, negative when
+/// the computer is ahead.
+pub fn leads(game: &Game) -> Vec<i8> {
+    let mut lead = 0i8;
+    game.history()
+        .iter()
+        .map(|record| {
+            match record.outcome().winner() {
+                Winner::Human => lead = lead.saturating_add(1),
+                Winner::Computer => lead = lead.saturating_sub(1),
+                Winner::Draw => {}
+            }
+            lead
+        })
+        .collect()
+}
+
+const EMPHASIS: &str = "\x1b[1;32m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Line describing a round, e.g. `1. Your choice: Rock, Computer choice: Paper`.
+///
+/// With `color` the winning choice is emphasized and the losing one dimmed
+/// using ANSI escape codes; drawn rounds are left plain.
+pub fn round_line(round: impl Display, outcome: &RoundOutcome, color: bool) -> String {
+    let paint = |choice: Choice, style: &str| {
+        if color {
+            format!("{}{}{}", style, choice, RESET)
+        } else {
+            choice.to_string()
+        }
+    };
+    let (human, computer) = match outcome.winner() {
+        Winner::Human => (
+            paint(outcome.human(), EMPHASIS),
+            paint(outcome.computer(), DIM),
+        ),
+        Winner::Computer => (
+            paint(outcome.human(), DIM),
+            paint(outcome.computer(), EMPHASIS),
+        ),
+        Winner::Draw => (outcome.human().to_string(), outcome.computer().to_string()),
+    };
+    format!(
+        "{}. Your choice: {}, Computer choice: {}",
+        round, human, computer
+    )
+}
+
+const ROCK_WINS: &[&str] = &["Crushing blow!", "Rock solid!", "Smashed to pieces!"];
+const PAPER_WINS: &[&str] = &["Wrapped up!", "A classic counter!", "Covered completely!"];
+const SCISSORS_WINS: &[&str] = &["A clean cut!", "Snip snip!", "Sliced right through!"];
+const DRAWS: &[&str] = &["Great minds think alike.", "A stalemate!", "Mirror match!"];
+
+/// Flavor line for a round, picked with `rng` from a small pool matching the
+/// winning choice, or from the draw pool.
+pub fn commentary(outcome: &RoundOutcome, rng: &mut impl Rng) -> &'static str {
+    let pool = match outcome.winner() {
+        Winner::Human => wins_with(outcome.human()),
+        Winner::Computer => wins_with(outcome.computer()),
+        Winner::Draw => DRAWS,
+    };
+    pool.choose(rng).copied().unwrap_or_default()
+}
+
+fn wins_with(choice: Choice) -> &'static [&'static str] {
+    match choice {
+        Choice::Rock => ROCK_WINS,
+        Choice::Paper => PAPER_WINS,
+        Choice::Scissors => SCISSORS_WINS,
+    }
+}
+
+/// Output format of a game, fed each round as it is played and the game
+/// once it is over.
+pub trait Renderer {
+    /// Called after every played round; does nothing by default.
+    fn round(&mut self, _round: u8, _outcome: &RoundOutcome) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn finish(&mut self, game: &Game) -> io::Result<()>;
+}
+
+/// Writes [`Game::summary_line`] when the game is over.
+pub struct OnelineRenderer<W> {
+    output: W,
+}
+
+impl<W: Write> OnelineRenderer<W> {
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+}
+
+impl<W: Write> Renderer for OnelineRenderer<W> {
+    fn finish(&mut self, game: &Game) -> io::Result<()> {
+        writeln!(self.output, "{}", game.summary_line())
+    }
+}
+
+/// Writes a `key=value` line as soon as each round is played, then the
+/// summary line.
+pub struct StreamRenderer<W> {
+    output: W,
+}
+
+impl<W: Write> StreamRenderer<W> {
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+}
+
+impl<W: Write> Renderer for StreamRenderer<W> {
+    fn round(&mut self, round: u8, outcome: &RoundOutcome) -> io::Result<()> {
+        writeln!(
+            self.output,
+            "round={} human={} computer={} winner={}",
+            round,
+            lowercase(outcome.human()),
+            lowercase(outcome.computer()),
+            lowercase(outcome.winner())
+        )?;
+        self.output.flush()
+    }
+
+    fn finish(&mut self, game: &Game) -> io::Result<()> {
+        writeln!(self.output, "{}", game.summary_line())
+    }
+}
+
+/// Writes the game history as CSV with a header row.
+pub struct CsvRenderer<W> {
+    output: W,
+}
+
+impl<W: Write> CsvRenderer<W> {
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+}
+
+impl<W: Write> Renderer for CsvRenderer<W> {
+    fn finish(&mut self, game: &Game) -> io::Result<()> {
+        writeln!(self.output, "round,human,computer,winner")?;
+        for record in game.history() {
+            let outcome = record.outcome();
+            writeln!(
+                self.output,
+                "{},{},{},{}",
+                record.round(),
+                lowercase(outcome.human()),
+                lowercase(outcome.computer()),
+                lowercase(outcome.winner())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the final score and the game history as a single JSON object.
+pub struct JsonRenderer<W> {
+    output: W,
+}
+
+impl<W: Write> JsonRenderer<W> {
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+}
+
+impl<W: Write> Renderer for JsonRenderer<W> {
+    fn finish(&mut self, game: &Game) -> io::Result<()> {
+        let rounds = game
+            .history()
+            .iter()
+            .map(|record| {
+                let outcome = record.outcome();
+                format!(
+                    r#"{{"round":{},"human":"{}","computer":"{}","winner":"{}"}}"#,
+                    record.round(),
+                    lowercase(outcome.human()),
+                    lowercase(outcome.computer()),
+                    lowercase(outcome.winner())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(
+            self.output,
+            r#"{{"best_of":{},"human":{},"computer":{},"draws":{},"winner":"{}","rounds":[{}]}}"#,
+            game.best_of(),
+            game.human_points(),
+            game.computer_points(),
+            game.draws(),
+            lowercase(game.game_winner()),
+            rounds
+        )
+    }
+}
+
+fn lowercase(value: impl Display) -> String {
+    value.to_string().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_length_scales_with_counts() {
+        let mut counts = HashMap::new();
+        counts.insert(Choice::Rock, 4);
+        counts.insert(Choice::Paper, 2);
+        assert_eq!(
+            histogram(&counts),
+            "Rock     ████ 4\nPaper    ██ 2\nScissors  0"
+        );
+
+        counts.insert(Choice::Scissors, 40);
+        let lines: Vec<_> = histogram(&counts).lines().map(String::from).collect();
+        assert_eq!(lines[0].matches('█').count(), 2);
+        assert_eq!(lines[2].matches('█').count(), 20);
+    }
+
+    #[test]
+    fn plural_uses_singular_only_for_one() {
+        assert_eq!(plural(1u8, "round", "rounds"), "1 round");
+        assert_eq!(plural(5u8, "round", "rounds"), "5 rounds");
+        assert_eq!(plural(0u32, "win", "wins"), "0 wins");
+    }
+
+    #[test]
+    fn margin_labels() {
+        assert_eq!(margin_label(3, 3), Some("Blowout!"));
+        assert_eq!(margin_label(1, 3), Some("Nail-biter!"));
+        assert_eq!(margin_label(2, 3), None);
+        assert_eq!(margin_label(0, 3), None);
+    }
+
+    #[test]
+    fn scoreboard_rows_list_rounds_then_totals_and_winner() {
+        let mut game = Game::new(None);
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        for _ in 0..3 {
+            game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        }
+        let rows = game.as_scoreboard_rows();
+
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            rows[0],
+            ScoreRow::Round {
+                round: 1,
+                human: Choice::Rock,
+                computer: Choice::Paper,
+                winner: Winner::Computer,
+            }
+        );
+        assert_eq!(
+            rows[4],
+            ScoreRow::Total {
+                human_points: 3,
+                computer_points: 1,
+                leader: Winner::Human,
+            }
+        );
+        assert_eq!(rows[5], ScoreRow::Winner(Winner::Human));
+    }
+
+    #[test]
+    fn sparkline_steps_up_from_lowest_value() {
+        assert_eq!(sparkline(&[0, 1, 2, 1, 0, 1, 2]), "▁▂▃▂▁▂▃");
+        assert_eq!(sparkline(&[-2, -1, 0]), "▁▂▃");
+        assert_eq!(sparkline(&[0, 10]), "▁█");
+        assert_eq!(sparkline(&[3, 3]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn leads_follow_the_score() {
+        let mut game = Game::new(None);
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert_eq!(leads(&game), vec![-1, -1, 0, 1]);
+    }
+
+    #[test]
+    fn round_line_highlights_winner_only_with_color() {
+        let outcome = RoundOutcome::new(Choice::Rock, Choice::Scissors, Winner::Human);
+        assert_eq!(
+            round_line(1, &outcome, false),
+            "1. Your choice: Rock, Computer choice: Scissors"
+        );
+        assert_eq!(
+            round_line(1, &outcome, true),
+            "1. Your choice: \x1b[1;32mRock\x1b[0m, Computer choice: \x1b[2mScissors\x1b[0m"
+        );
+
+        let draw = RoundOutcome::new(Choice::Paper, Choice::Paper, Winner::Draw);
+        assert_eq!(
+            round_line(2, &draw, true),
+            "2. Your choice: Paper, Computer choice: Paper"
+        );
+    }
+
+    #[test]
+    fn commentary_matches_outcome() {
+        let mut rng = rand::thread_rng();
+        let rock_win = RoundOutcome::new(Choice::Rock, Choice::Scissors, Winner::Human);
+        let scissors_win = RoundOutcome::new(Choice::Paper, Choice::Scissors, Winner::Computer);
+        let draw = RoundOutcome::new(Choice::Paper, Choice::Paper, Winner::Draw);
+
+        for _ in 0..10 {
+            assert!(ROCK_WINS.contains(&commentary(&rock_win, &mut rng)));
+            assert!(SCISSORS_WINS.contains(&commentary(&scissors_win, &mut rng)));
+            assert!(DRAWS.contains(&commentary(&draw, &mut rng)));
+        }
+        assert!(!commentary(&draw, &mut rng).is_empty());
+    }
+
+    fn sample_game(renderer: &mut dyn Renderer) -> Game {
+        let mut game = Game::best_of_rounds(3).unwrap();
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Paper, Choice::Paper),
+            (Choice::Paper, Choice::Rock),
+        ] {
+            let round = game.round_number();
+            let outcome = game.play_round(*human, *computer).unwrap();
+            renderer.round(round, &outcome).unwrap();
+        }
+        renderer.finish(&game).unwrap();
+        game
+    }
+
+    #[test]
+    fn stream_renderer_writes_rounds_then_summary() {
+        let mut output = Vec::new();
+        let game = sample_game(&mut StreamRenderer::new(&mut output));
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!(
+                "round=1 human=rock computer=scissors winner=human\n\
+                 round=2 human=paper computer=paper winner=draw\n\
+                 round=3 human=paper computer=rock winner=human\n\
+                 {}\n",
+                game.summary_line()
+            )
+        );
+    }
+
+    #[test]
+    fn csv_and_json_renderers_write_history() {
+        let mut csv = Vec::new();
+        sample_game(&mut CsvRenderer::new(&mut csv));
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "round,human,computer,winner\n\
+             1,rock,scissors,human\n\
+             2,paper,paper,draw\n\
+             3,paper,rock,human\n"
+        );
+
+        let mut json = Vec::new();
+        sample_game(&mut JsonRenderer::new(&mut json));
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            concat!(
+                r#"{"best_of":3,"human":2,"computer":0,"draws":1,"winner":"human","rounds":["#,
+                r#"{"round":1,"human":"rock","computer":"scissors","winner":"human"},"#,
+                r#"{"round":2,"human":"paper","computer":"paper","winner":"draw"},"#,
+                r#"{"round":3,"human":"paper","computer":"rock","winner":"human"}]}"#,
+                "\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use core::fmt::{Debug, Display};
+
+/// How the comparison of two choices is interpreted in a round.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rule {
+    /// The usual rules, rock beats scissors.
+    Normal,
+    /// The losing choice wins, scissors beat rock.
+    Inverted,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Rule::Normal => "Normal rules",
+                Rule::Inverted => "Inverted rules",
+            }
+        )
+    }
+}
+
+/// Decides which [`Rule`] is active in each round.
+pub trait RuleSchedule: Debug + Send + Sync {
+    fn rule(&self, round: u8) -> Rule;
+
+    /// Name a saved game stores the schedule under, see
+    /// [`Game::save`](crate::Game::save). Games with an unnamed schedule
+    /// can't be saved.
+    fn name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Normal rules on odd rounds, inverted rules on even rounds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlternatingRules;
+
+impl RuleSchedule for AlternatingRules {
+    fn rule(&self, round: u8) -> Rule {
+        if round.is_multiple_of(2) {
+            Rule::Inverted
+        } else {
+            Rule::Normal
+        }
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("alternating")
+    }
+}
@@ -0,0 +1,487 @@
+use crate::{
+    AlternatingRules, BestOf, Choice, DrawResolution, Game, RoundOutcome, RpsError, Scoring,
+    WinCondition, Winner,
+};
+use alloc::sync::Arc;
+use std::io::{self, BufRead, Write};
+
+/// Saved games are plain text: a `best_of=N` line followed by one
+/// `round=N human=CHOICE computer=CHOICE winner=WINNER` line per round.
+///
+/// Settings other than the defaults follow the best of on the first line, e.g.
+/// `best_of=5 win_condition=endless max_rounds=20 stop_early=false`:
+///
+/// - `tie_break`, the tie-break of an even best of, and `draw_resolution`,
+///   as `replay`, `random` or `favor-player`;
+/// - `rules`, the [name](crate::RuleSchedule::name) of the rule schedule;
+/// - `scoring`, as `half-point-draws` or `streak-bonus`;
+/// - `win_condition`, as `first-to:N`, `endless` or `adaptive:MARGIN,MAX`,
+///   and `adaptive_length`, the length an adaptive game was extended to;
+/// - `max_rounds` and `stop_early`;
+/// - `human_swapped=true` and `computer_swapped=true` for spent swaps.
+impl Game {
+    /// Fails with [`io::ErrorKind::InvalidInput`] when the game has a rule
+    /// schedule without a name.
+    pub fn save(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "best_of={}", self.best_of())?;
+        if let Some(tie_break) = self.best_of.tie_break() {
+            write!(writer, " tie_break={}", resolution_name(tie_break))?;
+        }
+        if let Some(resolution) = self.draw_resolution {
+            write!(writer, " draw_resolution={}", resolution_name(resolution))?;
+        }
+        if let Some(schedule) = &self.rule_schedule {
+            let name = schedule.name().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("rule schedule {:?} has no name to save", schedule),
+                )
+            })?;
+            write!(writer, " rules={}", name)?;
+        }
+        match self.scoring {
+            Scoring::WinsOnly => {}
+            Scoring::HalfPointDraws => write!(writer, " scoring=half-point-draws")?,
+            Scoring::StreakBonus => write!(writer, " scoring=streak-bonus")?,
+        }
+        match self.win_condition {
+            WinCondition::BestOf => {}
+            WinCondition::FirstTo(points) => write!(writer, " win_condition=first-to:{}", points)?,
+            WinCondition::Endless => write!(writer, " win_condition=endless")?,
+            WinCondition::AdaptiveLength { margin, max_length } => {
+                write!(writer, " win_condition=adaptive:{},{}", margin, max_length)?
+            }
+        }
+        if let Some(max_rounds) = self.max_rounds {
+            write!(writer, " max_rounds={}", max_rounds)?;
+        }
+        if !self.stop_early {
+            write!(writer, " stop_early=false")?;
+        }
+        if self.human_swapped {
+            write!(writer, " human_swapped=true")?;
+        }
+        if self.computer_swapped {
+            write!(writer, " computer_swapped=true")?;
+        }
+        if let Some(length) = self.adaptive_length {
+            write!(writer, " adaptive_length={}", length)?;
+        }
+        writeln!(writer)?;
+        for record in &self.history {
+            let outcome = record.outcome();
+            writeln!(
+                writer,
+                "round={} human={} computer={} winner={}",
+                record.round(),
+                outcome.human(),
+                outcome.computer(),
+                outcome.winner()
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Loads a game written by [`Game::save`]. Every round is decided again
+    /// from its choices under the saved rules, and a recorded winner that
+    /// doesn't match, or a round after the game was already over, is
+    /// rejected. Only a draw resolved by a coin flip keeps its recorded
+    /// winner.
+    pub fn load(reader: impl BufRead) -> Result<Game, RpsError> {
+        let mut lines = reader.lines();
+        let mut game = parse_settings(&read_line(lines.next())?)?;
+        for line in lines {
+            let line = read_line(Some(line))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields = parse_fields(&line)?;
+            if game.is_over() {
+                return Err(invalid("more rounds than the game allows"));
+            }
+            if fields.round != game.round.number {
+                return Err(invalid(&format!(
+                    "expected round {}, found round {}",
+                    game.round.number, fields.round
+                )));
+            }
+            let winner = match (
+                game.round_winner(&fields.human, &fields.computer),
+                game.draw_resolution,
+            ) {
+                (Winner::Draw, Some(DrawResolution::Replay)) => None,
+                (Winner::Draw, Some(DrawResolution::Random)) if fields.winner != Winner::Draw => {
+                    Some(fields.winner)
+                }
+                (Winner::Draw, Some(DrawResolution::Random)) => None,
+                (Winner::Draw, Some(DrawResolution::FavorPlayer)) => Some(Winner::Human),
+                (winner, _) => Some(winner),
+            };
+            if winner != Some(fields.winner) {
+                return Err(invalid(&format!(
+                    "round {} can't be won by {}",
+                    fields.round, fields.winner
+                )));
+            }
+            game.apply_outcome(RoundOutcome::new(
+                fields.human,
+                fields.computer,
+                fields.winner,
+            ));
+        }
+        Ok(game)
+    }
+}
+
+fn resolution_name(resolution: DrawResolution) -> &'static str {
+    match resolution {
+        DrawResolution::Replay => "replay",
+        DrawResolution::Random => "random",
+        DrawResolution::FavorPlayer => "favor-player",
+    }
+}
+
+fn parse_resolution(value: &str) -> Result<DrawResolution, RpsError> {
+    match value {
+        "replay" => Ok(DrawResolution::Replay),
+        "random" => Ok(DrawResolution::Random),
+        "favor-player" => Ok(DrawResolution::FavorPlayer),
+        _ => Err(invalid(&format!("invalid draw resolution '{}'", value))),
+    }
+}
+
+/// New game with the settings of the first line of a save.
+fn parse_settings(line: &str) -> Result<Game, RpsError> {
+    let mut fields = line.split_whitespace();
+    let best_of = match fields.next().and_then(|field| field.split_once('=')) {
+        Some(("best_of", value)) => {
+            BestOf::new_unchecked(value.parse().map_err(|_| invalid(line))?)?
+        }
+        _ => return Err(invalid("expected best_of on the first line")),
+    };
+    let mut game = Game::new(Some(best_of));
+    for field in fields {
+        match field.split_once('=') {
+            Some(("tie_break", value)) => {
+                let tie_break = parse_resolution(value)?;
+                game.best_of = BestOf::new_even_allowed(game.best_of(), Some(tie_break))?;
+                game.draw_resolution.get_or_insert(tie_break);
+            }
+            Some(("draw_resolution", value)) => {
+                game.draw_resolution = Some(parse_resolution(value)?)
+            }
+            Some(("rules", "alternating")) => game.rule_schedule = Some(Arc::new(AlternatingRules)),
+            Some(("scoring", "half-point-draws")) => game.scoring = Scoring::HalfPointDraws,
+            Some(("scoring", "streak-bonus")) => game.scoring = Scoring::StreakBonus,
+            Some(("win_condition", value)) => {
+                game = game.try_with_win_condition(parse_win_condition(value)?)?
+            }
+            Some(("max_rounds", value)) => {
+                game = game.try_with_max_rounds(value.parse().map_err(|_| invalid(line))?)?
+            }
+            Some(("stop_early", "false")) => game.stop_early = false,
+            Some(("human_swapped", "true")) => game.human_swapped = true,
+            Some(("computer_swapped", "true")) => game.computer_swapped = true,
+            Some(("adaptive_length", value)) => {
+                game.adaptive_length = Some(value.parse().map_err(|_| invalid(line))?)
+            }
+            _ => return Err(invalid(&format!("unexpected setting '{}'", field))),
+        }
+    }
+    Ok(game)
+}
+
+fn parse_win_condition(value: &str) -> Result<WinCondition, RpsError> {
+    let number = |text: &str| {
+        text.parse::<u8>()
+            .map_err(|_| invalid(&format!("invalid win condition '{}'", value)))
+    };
+    match value.split_once(':') {
+        None if value == "endless" => Ok(WinCondition::Endless),
+        Some(("first-to", points)) => Ok(WinCondition::FirstTo(number(points)?)),
+        Some(("adaptive", limits)) => match limits.split_once(',') {
+            Some((margin, max_length)) => Ok(WinCondition::AdaptiveLength {
+                margin: number(margin)?,
+                max_length: number(max_length)?,
+            }),
+            None => Err(invalid(&format!("invalid win condition '{}'", value))),
+        },
+        _ => Err(invalid(&format!("invalid win condition '{}'", value))),
+    }
+}
+
+struct RoundFields {
+    round: u8,
+    human: Choice,
+    computer: Choice,
+    winner: Winner,
+}
+
+fn invalid(reason: &str) -> RpsError {
+    RpsError::InvalidSave(reason.to_string())
+}
+
+fn read_line(line: Option<io::Result<String>>) -> Result<String, RpsError> {
+    match line {
+        Some(Ok(line)) => Ok(line),
+        Some(Err(error)) => Err(invalid(&error.to_string())),
+        None => Err(invalid("file is empty")),
+    }
+}
+
+fn parse_fields(line: &str) -> Result<RoundFields, RpsError> {
+    let mut round = None;
+    let mut human = None;
+    let mut computer = None;
+    let mut winner = None;
+    for field in line.split_whitespace() {
+        match field.split_once('=') {
+            Some(("round", value)) => {
+                round = Some(value.parse().map_err(|_| invalid(line))?);
+            }
+            Some(("human", value)) => human = Some(value.parse()?),
+            Some(("computer", value)) => computer = Some(value.parse()?),
+            Some(("winner", value)) => winner = Some(value.parse()?),
+            _ => return Err(invalid(line)),
+        }
+    }
+    match (round, human, computer, winner) {
+        (Some(round), Some(human), Some(computer), Some(winner)) => Ok(RoundFields {
+            round,
+            human,
+            computer,
+            winner,
+        }),
+        _ => Err(invalid(line)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Swap;
+
+    #[test]
+    fn saved_game_loads_back() {
+        let mut game = Game::new(None);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Paper, Choice::Paper).unwrap();
+        game.play_round(Choice::Paper, Choice::Scissors).unwrap();
+
+        let mut saved = Vec::new();
+        game.save(&mut saved).unwrap();
+        let loaded = Game::load(saved.as_slice()).unwrap();
+
+        assert_eq!(loaded, game);
+        assert_eq!(loaded.history(), game.history());
+    }
+
+    #[test]
+    fn endless_game_loads_back() {
+        let mut game = Game::new(None)
+            .with_win_condition(WinCondition::Endless)
+            .with_max_rounds(20);
+        for _ in 0..7 {
+            game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        }
+
+        let mut saved = Vec::new();
+        game.save(&mut saved).unwrap();
+        let loaded = Game::load(saved.as_slice()).unwrap();
+
+        assert_eq!(loaded, game);
+        assert_eq!(loaded.history(), game.history());
+        assert_eq!(loaded.win_condition(), WinCondition::Endless);
+        assert_eq!(loaded.max_rounds(), Some(20));
+        assert!(!loaded.is_over());
+    }
+
+    #[test]
+    fn win_conditions_round_trip() {
+        for condition in &[
+            WinCondition::BestOf,
+            WinCondition::FirstTo(4),
+            WinCondition::AdaptiveLength {
+                margin: 1,
+                max_length: 9,
+            },
+        ] {
+            let game = Game::new(None).with_win_condition(*condition);
+            let mut saved = Vec::new();
+            game.save(&mut saved).unwrap();
+            assert_eq!(
+                Game::load(saved.as_slice()).unwrap().win_condition(),
+                *condition
+            );
+        }
+        assert!(Game::load("best_of=5 win_condition=sometimes\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn adaptive_extension_loads_back() {
+        let mut game = Game::new(Some(BestOf::new(3).unwrap())).with_win_condition(
+            WinCondition::AdaptiveLength {
+                margin: 0,
+                max_length: 9,
+            },
+        );
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Rock),
+            (Choice::Rock, Choice::Scissors),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+
+        let mut saved = Vec::new();
+        game.save(&mut saved).unwrap();
+        let loaded = Game::load(saved.as_slice()).unwrap();
+
+        assert!(String::from_utf8(saved)
+            .unwrap()
+            .contains(" adaptive_length=5"));
+        assert!(!loaded.is_over());
+        assert_eq!(loaded.rounds_remaining(), Some(1));
+    }
+
+    #[test]
+    fn spent_swaps_stay_spent_after_loading() {
+        let mut game = Game::new(None);
+        game.play_round_with_swap(Choice::Rock, Choice::Paper, Swap::Human(Choice::Scissors))
+            .unwrap();
+
+        let mut saved = Vec::new();
+        game.save(&mut saved).unwrap();
+        let loaded = Game::load(saved.as_slice()).unwrap();
+
+        assert!(!loaded.human_can_swap());
+        assert!(loaded.computer_can_swap());
+    }
+
+    fn round_trip(game: &Game) -> Game {
+        let mut saved = Vec::new();
+        game.save(&mut saved).unwrap();
+        Game::load(saved.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn scoring_round_trips() {
+        for scoring in &[Scoring::HalfPointDraws, Scoring::StreakBonus] {
+            let mut game = Game::new(Some(BestOf::new(9).unwrap())).with_scoring(*scoring);
+            for (human, computer) in &[
+                (Choice::Rock, Choice::Scissors),
+                (Choice::Rock, Choice::Scissors),
+                (Choice::Rock, Choice::Rock),
+                (Choice::Rock, Choice::Paper),
+            ] {
+                game.play_round(*human, *computer).unwrap();
+            }
+
+            let loaded = round_trip(&game);
+            assert_eq!(loaded.scoring(), *scoring);
+            assert_eq!(loaded.human_score(), game.human_score());
+            assert_eq!(loaded.computer_score(), game.computer_score());
+            assert_eq!(loaded.game_winner(), game.game_winner());
+        }
+    }
+
+    #[test]
+    fn draw_resolutions_round_trip() {
+        for resolution in &[
+            DrawResolution::Replay,
+            DrawResolution::Random,
+            DrawResolution::FavorPlayer,
+        ] {
+            let mut game = Game::new(None).with_draw_resolution(*resolution);
+            game.play_round(Choice::Rock, Choice::Rock).unwrap();
+            game.play_round(Choice::Rock, Choice::Paper).unwrap();
+
+            let loaded = round_trip(&game);
+            assert_eq!(loaded.draw_resolution(), Some(*resolution));
+            assert_eq!(loaded.history(), game.history());
+        }
+    }
+
+    #[test]
+    fn rule_schedule_round_trips() {
+        let mut game = Game::new(None).with_rule_schedule(AlternatingRules);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        let inverted = game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        assert_eq!(inverted.winner(), Winner::Human);
+
+        let loaded = round_trip(&game);
+        assert_eq!(loaded.history(), game.history());
+        assert_eq!(loaded.human_points(), 2);
+
+        #[derive(Debug)]
+        struct Unnamed;
+        impl crate::RuleSchedule for Unnamed {
+            fn rule(&self, _round: u8) -> crate::Rule {
+                crate::Rule::Normal
+            }
+        }
+        let unnamed = Game::new(None).with_rule_schedule(Unnamed);
+        assert!(unnamed.save(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn even_best_of_round_trips() {
+        let best_of = BestOf::new_even_allowed(4, Some(DrawResolution::Replay)).unwrap();
+        let mut game = Game::new(Some(best_of));
+        for (human, computer) in &[
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+            (Choice::Rock, Choice::Scissors),
+            (Choice::Rock, Choice::Paper),
+        ] {
+            game.play_round(*human, *computer).unwrap();
+        }
+
+        let loaded = round_trip(&game);
+        assert_eq!(loaded.best_of(), 4);
+        assert_eq!(loaded.draw_resolution(), Some(DrawResolution::Replay));
+        assert!(!loaded.is_over());
+    }
+
+    #[test]
+    fn recorded_winner_must_match_the_choices() {
+        for saved in &[
+            "best_of=5\nround=1 human=Rock computer=Scissors winner=Computer\n",
+            "best_of=5 draw_resolution=replay\nround=1 human=Rock computer=Rock winner=Draw\n",
+            "best_of=5 draw_resolution=random\nround=1 human=Rock computer=Rock winner=Draw\n",
+        ] {
+            assert!(matches!(
+                Game::load(saved.as_bytes()),
+                Err(RpsError::InvalidSave(_))
+            ));
+        }
+        let coin_flip =
+            "best_of=5 draw_resolution=random\nround=1 human=Rock computer=Rock winner=Computer\n";
+        assert!(Game::load(coin_flip.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn out_of_order_rounds_are_rejected() {
+        let saved = "best_of=5\nround=2 human=Rock computer=Rock winner=Draw\n";
+        assert!(matches!(
+            Game::load(saved.as_bytes()),
+            Err(RpsError::InvalidSave(_))
+        ));
+    }
+
+    #[test]
+    fn rounds_beyond_best_of_are_rejected() {
+        let mut saved = String::from("best_of=3\n");
+        for round in 1..=4 {
+            saved.push_str(&format!(
+                "round={} human=Rock computer=Rock winner=Draw\n",
+                round
+            ));
+        }
+        assert!(matches!(
+            Game::load(saved.as_bytes()),
+            Err(RpsError::InvalidSave(_))
+        ));
+    }
+}
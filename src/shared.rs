@@ -0,0 +1,61 @@
+use crate::{Checkpoint, Choice, Game, RoundOutcome, RpsError};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A [`Game`] that can be shared between threads.
+///
+/// Every method takes the single inner lock for the duration of the call only,
+/// so methods can be called freely from any thread. The guard returned by
+/// [`SharedGame::lock`] must be dropped before calling any other method on the
+/// same `SharedGame`, otherwise the calling thread deadlocks on itself.
+#[derive(Debug, Clone)]
+pub struct SharedGame(Arc<Mutex<Game>>);
+
+impl SharedGame {
+    pub fn new(game: Game) -> Self {
+        Self(Arc::new(Mutex::new(game)))
+    }
+
+    pub fn play_round(&self, human: Choice, computer: Choice) -> Result<RoundOutcome, RpsError> {
+        self.lock().play_round(human, computer)
+    }
+
+    pub fn snapshot(&self) -> Checkpoint {
+        self.lock().checkpoint()
+    }
+
+    /// Locks the game for operations spanning several calls.
+    pub fn lock(&self) -> MutexGuard<'_, Game> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn rounds_from_many_threads_are_all_recorded() {
+        let shared = SharedGame::new(Game::best_of_rounds(9).unwrap());
+        let handles: Vec<_> = [Choice::Scissors, Choice::Paper]
+            .iter()
+            .map(|computer| {
+                let (shared, computer) = (shared.clone(), *computer);
+                thread::spawn(move || {
+                    for _ in 0..3 {
+                        shared.play_round(Choice::Rock, computer).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut game = shared.lock();
+        assert_eq!(game.history().len(), 6);
+        assert_eq!(game.human_points(), 3);
+        assert_eq!(game.computer_points(), 3);
+        assert_eq!(format!("{}", game.round()), "7");
+    }
+}
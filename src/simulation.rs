@@ -0,0 +1,231 @@
+use crate::{BestOf, Game, Strategy, StrategyConfig, WinCondition, Winner};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+
+/// Aggregate results of many matches between two strategies.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TournamentResult {
+    pub first_wins: u32,
+    pub second_wins: u32,
+    pub draws: u32,
+}
+
+/// Plays `matches` headless matches between strategies created by `make_first`,
+/// playing the human side, and `make_second`, playing the computer side.
+///
+/// Fresh strategies are created for every match while a single game is reset
+/// and reused, and all randomness comes from `rng`, so a seeded RNG gives
+/// reproducible results.
+pub fn run_many<S1, S2, R>(
+    matches: usize,
+    best_of: BestOf,
+    mut make_first: impl FnMut() -> S1,
+    mut make_second: impl FnMut() -> S2,
+    rng: &mut R,
+) -> TournamentResult
+where
+    S1: Strategy,
+    S2: Strategy,
+    R: RngCore,
+{
+    let mut result = TournamentResult::default();
+    let mut game = Game::new(Some(best_of));
+    for _ in 0..matches {
+        game.reset();
+        let mut first = make_first();
+        let mut second = make_second();
+        while !game.is_over() {
+            let human = first.choose(rng);
+            let computer = second.choose(rng);
+            let outcome = match game.play_round_with_rng(human, computer, rng) {
+                Ok(outcome) => outcome,
+                Err(_) => break,
+            };
+            first.notify(&outcome.mirrored());
+            second.notify(&outcome);
+        }
+        match game.game_winner() {
+            Winner::Human => result.first_wins += 1,
+            Winner::Computer => result.second_wins += 1,
+            Winner::Draw => result.draws += 1,
+        }
+    }
+    result
+}
+
+/// Results of [`round_robin`]: one [`TournamentResult`] for every ordered
+/// pairing of strategies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    strategies: Vec<StrategyConfig>,
+    matches: usize,
+    results: Vec<TournamentResult>,
+}
+
+impl Grid {
+    pub fn strategies(&self) -> &[StrategyConfig] {
+        &self.strategies
+    }
+
+    /// Matches played by every pairing.
+    pub fn matches(&self) -> usize {
+        self.matches
+    }
+
+    /// Results of the strategy at index `first`, playing the human side,
+    /// against the one at index `second`.
+    pub fn result(&self, first: usize, second: usize) -> TournamentResult {
+        self.results[first * self.strategies.len() + second]
+    }
+
+    /// Percentage of the matches between `first` and `second` won by `first`.
+    pub fn win_percentage(&self, first: usize, second: usize) -> f64 {
+        match self.matches {
+            0 => 0.0,
+            matches => 100.0 * f64::from(self.result(first, second).first_wins) / matches as f64,
+        }
+    }
+}
+
+/// Plays [`run_many`] for every ordered pairing of `strategies`, including
+/// each strategy against itself. All pairings draw from one RNG seeded with
+/// `seed`, so the grid is reproducible.
+pub fn round_robin(
+    strategies: &[StrategyConfig],
+    best_of: BestOf,
+    matches: usize,
+    seed: u64,
+) -> Grid {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut results = Vec::with_capacity(strategies.len() * strategies.len());
+    for first in strategies {
+        for second in strategies {
+            results.push(run_many(
+                matches,
+                best_of,
+                || first.build(),
+                || second.build(),
+                &mut rng,
+            ));
+        }
+    }
+    Grid {
+        strategies: strategies.to_vec(),
+        matches,
+        results,
+    }
+}
+
+impl Game {
+    /// Estimates the human's chance of winning the match by playing `samples`
+    /// rollouts of the remaining rounds with uniformly random choices for
+    /// both sides, returning the fraction won by the human. Rollouts keep the
+    /// game's rule schedule and draw resolution, flipping any coins with `rng`.
+    ///
+    /// Endless games can't be played to completion, so they report 1.0 when
+    /// the human currently leads and 0.0 otherwise. No samples give 0.0.
+    pub fn win_probability(&self, samples: usize, rng: &mut impl Rng) -> f64 {
+        if self.win_condition == WinCondition::Endless {
+            return if self.game_winner() == Winner::Human {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        if samples == 0 {
+            return 0.0;
+        }
+
+        let mut rollout = self.clone();
+        let start = rollout.checkpoint();
+        let mut human_wins = 0;
+        for _ in 0..samples {
+            rollout.restore(start);
+            while rollout
+                .play_round_with_rng(rng.gen(), rng.gen(), rng)
+                .is_ok()
+            {}
+            if rollout.game_winner() == Winner::Human {
+                human_wins += 1;
+            }
+        }
+        f64::from(human_wins) / samples as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Choice, FrequencyStrategy, RandomStrategy};
+
+    #[test]
+    fn results_are_deterministic_for_fixed_seed() {
+        let run = || {
+            run_many(
+                200,
+                BestOf::default(),
+                FrequencyStrategy::default,
+                || RandomStrategy,
+                &mut StdRng::seed_from_u64(7),
+            )
+        };
+        let result = run();
+
+        assert_eq!(result, run());
+        assert_eq!(result.first_wins + result.second_wins + result.draws, 200);
+    }
+
+    #[test]
+    fn round_robin_counters_always_rock() {
+        let strategies = [
+            StrategyConfig::AlwaysRock,
+            StrategyConfig::Random,
+            StrategyConfig::Frequency { window: None },
+        ];
+        let grid = round_robin(&strategies, BestOf::default(), 200, 7);
+
+        assert_eq!(grid, round_robin(&strategies, BestOf::default(), 200, 7));
+        assert_eq!(grid.result(0, 0).draws, 200);
+        assert!(grid.win_percentage(2, 0) > 90.0, "{:?}", grid.result(2, 0));
+        assert!(grid.win_percentage(2, 0) > grid.win_percentage(1, 0));
+        assert_eq!(
+            grid.win_percentage(0, 2),
+            100.0 * f64::from(grid.result(0, 2).first_wins) / 200.0
+        );
+    }
+
+    #[test]
+    fn win_probability_is_certain_after_decisive_lead() {
+        let mut game = Game::best_of_rounds(3).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+
+        assert_eq!(
+            game.win_probability(100, &mut StdRng::seed_from_u64(7)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn win_probability_is_balanced_at_start() {
+        let game = Game::best_of_rounds(5).unwrap();
+        let probability = game.win_probability(2000, &mut StdRng::seed_from_u64(7));
+
+        // Drawn matches count as not won, so an even match is below one half.
+        assert!(probability > 0.3 && probability < 0.5, "{}", probability);
+        assert_eq!(game.rounds_played(), 0);
+    }
+
+    #[test]
+    fn win_probability_keeps_rules_and_seeded_draw_resolution() {
+        let mut game = Game::best_of_rounds(5)
+            .unwrap()
+            .with_rule_schedule(crate::AlternatingRules)
+            .with_draw_resolution(crate::DrawResolution::Random);
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        assert_eq!(game.clone().active_rule(), crate::Rule::Inverted);
+
+        let probability = |seed| game.win_probability(500, &mut StdRng::seed_from_u64(seed));
+        assert_eq!(probability(3), probability(3));
+    }
+}
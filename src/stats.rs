@@ -0,0 +1,141 @@
+use crate::{Choice, RoundRecord, Winner};
+use std::collections::HashMap;
+
+/// Wins, losses and draws of the human player.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChoiceBreakdown {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl ChoiceBreakdown {
+    pub fn rounds(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        match self.rounds() {
+            0 => 0.0,
+            rounds => f64::from(self.wins) / f64::from(rounds),
+        }
+    }
+
+    pub(crate) fn record(&mut self, winner: Winner) {
+        match winner {
+            Winner::Human => self.wins += 1,
+            Winner::Computer => self.losses += 1,
+            Winner::Draw => self.draws += 1,
+        }
+    }
+}
+
+/// Statistics of the human player computed from the game history.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameStats {
+    overall: ChoiceBreakdown,
+    by_choice: HashMap<Choice, ChoiceBreakdown>,
+}
+
+impl GameStats {
+    pub fn from_history(history: &[RoundRecord]) -> Self {
+        let mut stats = Self::default();
+        for record in history {
+            let outcome = record.outcome();
+            stats.overall.record(outcome.winner());
+            stats
+                .by_choice
+                .entry(outcome.human())
+                .or_default()
+                .record(outcome.winner());
+        }
+        stats
+    }
+
+    pub fn overall(&self) -> ChoiceBreakdown {
+        self.overall
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.overall.win_rate()
+    }
+
+    /// Outcomes of the rounds in which the human played `choice`.
+    pub fn by_choice(&self, choice: Choice) -> ChoiceBreakdown {
+        self.by_choice.get(&choice).copied().unwrap_or_default()
+    }
+
+    /// How many times the human played each choice.
+    pub fn choice_counts(&self) -> HashMap<Choice, u32> {
+        self.by_choice
+            .iter()
+            .map(|(choice, breakdown)| (*choice, breakdown.rounds()))
+            .collect()
+    }
+
+    /// Shannon entropy in bits of the human's choices, from 0 for always
+    /// playing the same move up to [`GameStats::max_entropy`] for playing all
+    /// three equally often.
+    pub fn human_entropy(&self) -> f64 {
+        let rounds = f64::from(self.overall.rounds());
+        self.by_choice
+            .values()
+            .map(|breakdown| f64::from(breakdown.rounds()) / rounds)
+            .filter(|probability| *probability > 0.0)
+            .map(|probability| -probability * probability.log2())
+            .sum()
+    }
+
+    /// Entropy of three equally likely choices, `log2(3)`.
+    pub fn max_entropy() -> f64 {
+        3f64.log2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+
+    #[test]
+    fn breakdown_is_keyed_by_human_choice() {
+        let mut game = Game::new(Some("7".parse().unwrap()));
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Scissors).unwrap();
+        game.play_round(Choice::Paper, Choice::Rock).unwrap();
+
+        let stats = game.stats();
+        let rock = stats.by_choice(Choice::Rock);
+        assert_eq!(
+            rock,
+            ChoiceBreakdown {
+                wins: 2,
+                losses: 1,
+                draws: 1
+            }
+        );
+        assert_eq!(rock.win_rate(), 0.5);
+        assert_eq!(stats.by_choice(Choice::Scissors).rounds(), 0);
+        assert_eq!(stats.overall().wins, 3);
+    }
+
+    #[test]
+    fn entropy_follows_choice_distribution() {
+        let mut game = Game::new(Some("7".parse().unwrap()));
+        for choice in &[Choice::Rock, Choice::Paper, Choice::Scissors] {
+            game.play_round(*choice, *choice).unwrap();
+            game.play_round(*choice, *choice).unwrap();
+        }
+        let uniform = game.stats().human_entropy();
+        assert!((uniform - GameStats::max_entropy()).abs() < 1e-9);
+        assert!((uniform - 1.585).abs() < 1e-3);
+
+        let mut game = Game::new(None);
+        game.play_round(Choice::Rock, Choice::Rock).unwrap();
+        game.play_round(Choice::Rock, Choice::Paper).unwrap();
+        assert_eq!(game.stats().human_entropy(), 0.0);
+        assert_eq!(Game::new(None).stats().human_entropy(), 0.0);
+    }
+}
@@ -0,0 +1,834 @@
+use crate::{Choice, RoundOutcome, RpsError, Winner};
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+const CHOICES: [Choice; 3] = [Choice::Rock, Choice::Paper, Choice::Scissors];
+
+/// Decides the computer's choice for each round.
+pub trait Strategy: Debug {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice;
+
+    /// Called after every round so the strategy can learn from it.
+    fn notify(&mut self, _outcome: &RoundOutcome) {}
+
+    /// Human readable explanation of the last decision, if the strategy has one.
+    fn rationale(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Boxed strategies, like the ones from [`StrategyConfig::build`], are
+/// strategies too.
+impl<S: Strategy + ?Sized> Strategy for Box<S> {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        (**self).choose(rng)
+    }
+
+    fn notify(&mut self, outcome: &RoundOutcome) {
+        (**self).notify(outcome)
+    }
+
+    fn rationale(&self) -> Option<String> {
+        (**self).rationale()
+    }
+}
+
+/// Creates a strategy from its command line name, see [`StrategyConfig`].
+pub fn strategy_by_name(name: &str) -> Result<Box<dyn Strategy>, RpsError> {
+    Ok(name.parse::<StrategyConfig>()?.build())
+}
+
+/// Name and one-line description of a built-in strategy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrategyInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// Every built-in strategy, in the order they are listed to the user.
+pub fn strategies() -> Vec<StrategyInfo> {
+    let info = |name, description| StrategyInfo { name, description };
+    vec![
+        info("random", "Picks uniformly at random"),
+        info("always-rock", "Always plays Rock"),
+        info(
+            "frequency",
+            "Counters your most frequent choice, 'frequency:N' counts only the last N",
+        ),
+        info(
+            "weighted",
+            "Picks at random with the given weights, e.g. 'weighted:1,2,3'",
+        ),
+        info("markov", "Predicts your next choice from your previous one"),
+        info(
+            "mirror",
+            "Copies your previous choice, to practice countering it",
+        ),
+        info(
+            "shuffle-bag",
+            "Plays every choice equally often, 'shuffle-bag:N' puts N copies in the bag",
+        ),
+        info(
+            "boss",
+            "Plays at random until 2 points behind, then counters your favorite choice",
+        ),
+    ]
+}
+
+/// Complete description of a built-in strategy and its parameters.
+///
+/// It is written and parsed as the strategy name optionally followed by its
+/// parameters, e.g. `random`, `frequency:5` or `weighted:1,2,3`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StrategyConfig {
+    Random,
+    AlwaysRock,
+    /// Counts only the last `window` human choices when set.
+    Frequency {
+        window: Option<usize>,
+    },
+    /// Relative weights of Rock, Paper and Scissors.
+    Weighted {
+        weights: [u32; 3],
+    },
+    Markov,
+    ShuffleBag {
+        copies: usize,
+    },
+    Mirror,
+    Boss,
+}
+
+impl StrategyConfig {
+    pub fn build(&self) -> Box<dyn Strategy> {
+        match self {
+            StrategyConfig::Random => Box::new(RandomStrategy),
+            StrategyConfig::AlwaysRock => Box::new(AlwaysRockStrategy),
+            StrategyConfig::Frequency { window: None } => Box::new(FrequencyStrategy::default()),
+            StrategyConfig::Frequency {
+                window: Some(window),
+            } => Box::new(FrequencyStrategy::with_window(*window)),
+            StrategyConfig::Weighted { weights } => Box::new(WeightedStrategy::new(*weights)),
+            StrategyConfig::Markov => Box::new(MarkovStrategy::default()),
+            StrategyConfig::ShuffleBag { copies } => Box::new(ShuffleBagStrategy::new(*copies)),
+            StrategyConfig::Mirror => Box::new(MirrorStrategy::default()),
+            StrategyConfig::Boss => Box::new(AdaptiveStrategy::default()),
+        }
+    }
+}
+
+impl Display for StrategyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrategyConfig::Random => write!(f, "random"),
+            StrategyConfig::AlwaysRock => write!(f, "always-rock"),
+            StrategyConfig::Frequency { window: None } => write!(f, "frequency"),
+            StrategyConfig::Frequency {
+                window: Some(window),
+            } => write!(f, "frequency:{}", window),
+            StrategyConfig::Weighted { weights: [r, p, s] } => {
+                write!(f, "weighted:{},{},{}", r, p, s)
+            }
+            StrategyConfig::Markov => write!(f, "markov"),
+            StrategyConfig::ShuffleBag { copies } => write!(f, "shuffle-bag:{}", copies),
+            StrategyConfig::Mirror => write!(f, "mirror"),
+            StrategyConfig::Boss => write!(f, "boss"),
+        }
+    }
+}
+
+impl FromStr for StrategyConfig {
+    type Err = RpsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || RpsError::UnknownStrategy(s.to_string());
+        let lowercase = s.trim().to_lowercase();
+        let (name, parameters) = match lowercase.split_once(':') {
+            Some((name, parameters)) => (name, Some(parameters)),
+            None => (lowercase.as_str(), None),
+        };
+        if !strategies().iter().any(|info| info.name == name) {
+            return Err(unknown());
+        }
+        match (name, parameters) {
+            ("random", None) => Ok(StrategyConfig::Random),
+            ("always-rock", None) => Ok(StrategyConfig::AlwaysRock),
+            ("frequency", None) => Ok(StrategyConfig::Frequency { window: None }),
+            ("frequency", Some(window)) => Ok(StrategyConfig::Frequency {
+                window: Some(window.parse().map_err(|_| unknown())?),
+            }),
+            ("weighted", Some(weights)) => {
+                let weights = weights
+                    .split(',')
+                    .map(|weight| weight.trim().parse().map_err(|_| unknown()))
+                    .collect::<Result<Vec<u32>, _>>()?;
+                match weights.as_slice() {
+                    [r, p, s] => Ok(StrategyConfig::Weighted {
+                        weights: [*r, *p, *s],
+                    }),
+                    _ => Err(unknown()),
+                }
+            }
+            ("markov", None) => Ok(StrategyConfig::Markov),
+            ("mirror", None) => Ok(StrategyConfig::Mirror),
+            ("boss", None) => Ok(StrategyConfig::Boss),
+            ("shuffle-bag", None) => Ok(StrategyConfig::ShuffleBag { copies: 2 }),
+            ("shuffle-bag", Some(copies)) => Ok(StrategyConfig::ShuffleBag {
+                copies: copies.parse().map_err(|_| unknown())?,
+            }),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+fn counter(choice: Choice) -> Choice {
+    match choice {
+        Choice::Rock => Choice::Paper,
+        Choice::Paper => Choice::Scissors,
+        Choice::Scissors => Choice::Rock,
+    }
+}
+
+/// The choice that loses to `choice` under the normal [`RULES`](crate::RULES).
+fn loses_to(choice: Choice) -> Choice {
+    for rule in crate::RULES {
+        if rule.winner == choice {
+            return rule.loser;
+        }
+    }
+    unreachable!("every choice beats another one")
+}
+
+/// Picks uniformly at random.
+#[derive(Debug, Default)]
+pub struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        rng.gen()
+    }
+}
+
+/// Always plays Rock.
+#[derive(Debug, Default)]
+pub struct AlwaysRockStrategy;
+
+impl Strategy for AlwaysRockStrategy {
+    fn choose(&mut self, _rng: &mut dyn RngCore) -> Choice {
+        Choice::Rock
+    }
+}
+
+/// Copies the human's previous choice, picking at random in the first round.
+#[derive(Debug, Default)]
+pub struct MirrorStrategy {
+    last_human: Option<Choice>,
+}
+
+impl Strategy for MirrorStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        self.last_human.unwrap_or_else(|| rng.gen())
+    }
+
+    fn notify(&mut self, outcome: &RoundOutcome) {
+        self.last_human = Some(outcome.human());
+    }
+
+    fn rationale(&self) -> Option<String> {
+        self.last_human
+            .map(|choice| format!("You played {} last time, so I'll play it too.", choice))
+    }
+}
+
+/// Picks at random with fixed relative weights for Rock, Paper and Scissors.
+#[derive(Debug)]
+pub struct WeightedStrategy {
+    weights: [u32; 3],
+}
+
+impl WeightedStrategy {
+    /// All weights being zero is treated as equal weights.
+    pub fn new(weights: [u32; 3]) -> Self {
+        Self { weights }
+    }
+}
+
+impl Strategy for WeightedStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        let total: u32 = self.weights.iter().sum();
+        if total == 0 {
+            return rng.gen();
+        }
+        let mut roll = rng.gen_range(0..total);
+        for (choice, weight) in CHOICES.iter().zip(self.weights.iter()) {
+            if roll < *weight {
+                return *choice;
+            }
+            roll -= weight;
+        }
+        unreachable!("roll is always below the total weight")
+    }
+}
+
+/// Plays the counter to the human's most frequent choice so far.
+#[derive(Debug)]
+pub struct FrequencyStrategy {
+    window: Option<usize>,
+    decay: f64,
+    /// The last `window` human choices, only kept with a window.
+    recent: VecDeque<Choice>,
+    /// Decayed counts of all human choices by [`Choice::to_bits`], only kept
+    /// without a window.
+    weights: [f64; 3],
+    last_decision: Option<(Choice, Choice)>,
+}
+
+/// Counts every choice equally, without a window.
+impl Default for FrequencyStrategy {
+    fn default() -> Self {
+        Self {
+            window: None,
+            decay: 1.0,
+            recent: VecDeque::new(),
+            weights: [0.0; 3],
+            last_decision: None,
+        }
+    }
+}
+
+impl FrequencyStrategy {
+    /// Counts only the last `window` human choices.
+    pub fn with_window(window: usize) -> Self {
+        Self {
+            window: Some(window.max(1)),
+            ..Self::default()
+        }
+    }
+
+    /// Weighs each human choice by `decay` to the power of its age, so with a
+    /// decay below 1 recent choices count more and the strategy follows a
+    /// change of tactics sooner. The latest choice has age 0 and weight 1;
+    /// `decay` is clamped to between 0 and 1.
+    pub fn with_decay(decay: f64) -> Self {
+        Self {
+            decay: decay.clamp(0.0, 1.0),
+            ..Self::default()
+        }
+    }
+
+    fn weight(&self, choice: Choice) -> f64 {
+        if self.window.is_none() {
+            return self.weights[usize::from(choice.to_bits())];
+        }
+        self.recent
+            .iter()
+            .rev()
+            .zip(0..)
+            .filter(|(recent, _)| **recent == choice)
+            .map(|(_, age)| self.decay.powi(age))
+            .sum()
+    }
+}
+
+impl Strategy for FrequencyStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        let favourite = CHOICES
+            .iter()
+            .map(|choice| (*choice, self.weight(*choice)))
+            .filter(|(_, weight)| *weight > 0.0)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(choice, _)| choice);
+
+        match favourite {
+            Some(favourite) => {
+                let choice = counter(favourite);
+                self.last_decision = Some((favourite, choice));
+                choice
+            }
+            None => {
+                self.last_decision = None;
+                rng.gen()
+            }
+        }
+    }
+
+    fn notify(&mut self, outcome: &RoundOutcome) {
+        match self.window {
+            Some(window) => {
+                self.recent.push_back(outcome.human());
+                while self.recent.len() > window {
+                    self.recent.pop_front();
+                }
+            }
+            None => {
+                for weight in &mut self.weights {
+                    *weight *= self.decay;
+                }
+                self.weights[usize::from(outcome.human().to_bits())] += 1.0;
+            }
+        }
+    }
+
+    fn rationale(&self) -> Option<String> {
+        self.last_decision.map(|(favourite, choice)| {
+            format!(
+                "You've played {} a lot, so I'll play {}.",
+                favourite, choice
+            )
+        })
+    }
+}
+
+/// Predicts the human's next choice from what they played after their
+/// previous choice so far, and plays its counter.
+#[derive(Debug, Default)]
+pub struct MarkovStrategy {
+    transitions: [[u32; 3]; 3],
+    previous: Option<Choice>,
+    last_decision: Option<(Choice, Choice, Choice)>,
+}
+
+impl Strategy for MarkovStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        let prediction = self.previous.and_then(|previous| {
+            let row = &self.transitions[previous as usize];
+            CHOICES
+                .iter()
+                .copied()
+                .filter(|next| row[*next as usize] > 0)
+                .max_by_key(|next| row[*next as usize])
+                .map(|next| (previous, next))
+        });
+
+        match prediction {
+            Some((previous, predicted)) => {
+                let choice = counter(predicted);
+                self.last_decision = Some((previous, predicted, choice));
+                choice
+            }
+            None => {
+                self.last_decision = None;
+                rng.gen()
+            }
+        }
+    }
+
+    fn notify(&mut self, outcome: &RoundOutcome) {
+        if let Some(previous) = self.previous {
+            self.transitions[previous as usize][outcome.human() as usize] += 1;
+        }
+        self.previous = Some(outcome.human());
+    }
+
+    fn rationale(&self) -> Option<String> {
+        self.last_decision.map(|(previous, predicted, choice)| {
+            format!(
+                "After {} you usually play {}, so I'll play {}.",
+                previous, predicted, choice
+            )
+        })
+    }
+}
+
+/// Wraps another strategy and, with the given probability, deliberately
+/// plays the move that loses to the human's last choice.
+#[derive(Debug)]
+pub struct LuckyStrategy {
+    inner: Box<dyn Strategy>,
+    probability: f64,
+    last_human: Option<Choice>,
+    gave_break: bool,
+}
+
+impl LuckyStrategy {
+    /// `probability` is clamped to the `0.0..=1.0` range.
+    pub fn new(inner: Box<dyn Strategy>, probability: f64) -> Self {
+        Self {
+            inner,
+            probability: probability.clamp(0.0, 1.0),
+            last_human: None,
+            gave_break: false,
+        }
+    }
+}
+
+impl Strategy for LuckyStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        let inner_choice = self.inner.choose(rng);
+        match self.last_human {
+            Some(human) if rng.gen_bool(self.probability) => {
+                self.gave_break = true;
+                loses_to(human)
+            }
+            _ => {
+                self.gave_break = false;
+                inner_choice
+            }
+        }
+    }
+
+    fn notify(&mut self, outcome: &RoundOutcome) {
+        self.last_human = Some(outcome.human());
+        self.inner.notify(outcome);
+    }
+
+    fn rationale(&self) -> Option<String> {
+        if self.gave_break {
+            Some("Feeling lucky? I'll go easy on you this time.".to_string())
+        } else {
+            self.inner.rationale()
+        }
+    }
+}
+
+/// Draws from a shuffled bag holding every choice the same number of times,
+/// refilling it once empty, so no choice is played much more than the others.
+#[derive(Debug)]
+pub struct ShuffleBagStrategy {
+    copies: usize,
+    bag: Vec<Choice>,
+}
+
+impl ShuffleBagStrategy {
+    /// Creates a bag holding `copies` of each choice.
+    pub fn new(copies: usize) -> Self {
+        Self {
+            copies: copies.max(1),
+            bag: Vec::with_capacity(copies.max(1) * 3),
+        }
+    }
+}
+
+impl Default for ShuffleBagStrategy {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl Strategy for ShuffleBagStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        if self.bag.is_empty() {
+            for choice in &CHOICES {
+                self.bag.extend(std::iter::repeat_n(*choice, self.copies));
+            }
+            self.bag.shuffle(rng);
+        }
+        self.bag.pop().unwrap()
+    }
+}
+
+/// Plays `calm` until the computer falls `deficit` or more points behind,
+/// then `behind` until it catches up again. Both strategies learn from every
+/// round, so the one switched to is ready to play.
+#[derive(Debug)]
+pub struct AdaptiveStrategy {
+    calm: Box<dyn Strategy>,
+    behind: Box<dyn Strategy>,
+    deficit: u8,
+    human_points: u8,
+    computer_points: u8,
+}
+
+impl AdaptiveStrategy {
+    pub fn new(calm: Box<dyn Strategy>, behind: Box<dyn Strategy>, deficit: u8) -> Self {
+        Self {
+            calm,
+            behind,
+            deficit,
+            human_points: 0,
+            computer_points: 0,
+        }
+    }
+
+    /// Whether the computer is far enough behind to play the `behind` strategy.
+    pub fn is_behind(&self) -> bool {
+        self.human_points.saturating_sub(self.computer_points) >= self.deficit
+    }
+
+    fn active(&mut self) -> &mut dyn Strategy {
+        if self.is_behind() {
+            self.behind.as_mut()
+        } else {
+            self.calm.as_mut()
+        }
+    }
+}
+
+/// The "boss": random while ahead, countering the human's most frequent
+/// choice once 2 points behind.
+impl Default for AdaptiveStrategy {
+    fn default() -> Self {
+        Self::new(
+            Box::new(RandomStrategy),
+            Box::new(FrequencyStrategy::default()),
+            2,
+        )
+    }
+}
+
+impl Strategy for AdaptiveStrategy {
+    fn choose(&mut self, rng: &mut dyn RngCore) -> Choice {
+        self.active().choose(rng)
+    }
+
+    fn notify(&mut self, outcome: &RoundOutcome) {
+        match outcome.winner() {
+            Winner::Human => self.human_points = self.human_points.saturating_add(1),
+            Winner::Computer => self.computer_points = self.computer_points.saturating_add(1),
+            Winner::Draw => {}
+        }
+        self.calm.notify(outcome);
+        self.behind.notify(outcome);
+    }
+
+    fn rationale(&self) -> Option<String> {
+        if self.is_behind() {
+            Some(match self.behind.rationale() {
+                Some(rationale) => format!("No more games. {}", rationale),
+                None => "No more games.".to_string(),
+            })
+        } else {
+            self.calm.rationale()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Game, Winner};
+    use std::collections::HashMap;
+
+    #[test]
+    fn frequency_strategy_explains_biased_play() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::best_of_rounds(7).unwrap();
+        let mut strategy = FrequencyStrategy::default();
+        for human in &[Choice::Scissors, Choice::Scissors, Choice::Rock] {
+            let computer = strategy.choose(&mut rng);
+            strategy.notify(&game.play_round(*human, computer).unwrap());
+        }
+
+        assert_eq!(strategy.choose(&mut rng), Choice::Rock);
+        assert_eq!(
+            strategy.rationale().unwrap(),
+            "You've played Scissors a lot, so I'll play Rock."
+        );
+    }
+
+    #[test]
+    fn random_strategy_has_no_rationale() {
+        let mut strategy = RandomStrategy;
+        strategy.choose(&mut rand::thread_rng());
+        assert_eq!(strategy.rationale(), None);
+    }
+
+    #[test]
+    fn shuffle_bag_is_balanced_over_full_cycle() {
+        let mut rng = rand::thread_rng();
+        let mut strategy = ShuffleBagStrategy::new(3);
+        for _ in 0..2 {
+            let mut counts = HashMap::new();
+            for _ in 0..9 {
+                *counts.entry(strategy.choose(&mut rng)).or_insert(0) += 1;
+            }
+            assert_eq!(counts[&Choice::Rock], 3);
+            assert_eq!(counts[&Choice::Paper], 3);
+            assert_eq!(counts[&Choice::Scissors], 3);
+        }
+    }
+
+    #[test]
+    fn strategy_config_round_trips_through_text() {
+        for config in &[
+            StrategyConfig::Random,
+            StrategyConfig::AlwaysRock,
+            StrategyConfig::Frequency { window: None },
+            StrategyConfig::Frequency { window: Some(5) },
+            StrategyConfig::Weighted { weights: [1, 2, 3] },
+            StrategyConfig::Markov,
+            StrategyConfig::ShuffleBag { copies: 4 },
+            StrategyConfig::Mirror,
+            StrategyConfig::Boss,
+        ] {
+            assert_eq!(
+                config.to_string().parse::<StrategyConfig>(),
+                Ok(config.clone())
+            );
+        }
+        assert!("weighted:1,2".parse::<StrategyConfig>().is_err());
+        assert!("frequency:x".parse::<StrategyConfig>().is_err());
+    }
+
+    #[test]
+    fn strategy_config_builds_expected_strategy() {
+        let built = StrategyConfig::Weighted { weights: [0, 5, 0] }.build();
+        assert_eq!(
+            format!("{:?}", built),
+            "WeightedStrategy { weights: [0, 5, 0] }"
+        );
+
+        let mut always_rock = StrategyConfig::AlwaysRock.build();
+        assert_eq!(format!("{:?}", always_rock), "AlwaysRockStrategy");
+        assert_eq!(always_rock.choose(&mut rand::thread_rng()), Choice::Rock);
+
+        let markov = StrategyConfig::Markov.build();
+        assert!(format!("{:?}", markov).starts_with("MarkovStrategy"));
+    }
+
+    #[test]
+    fn markov_strategy_counters_learned_transition() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::best_of_rounds(9).unwrap();
+        let mut strategy = MarkovStrategy::default();
+        for human in &[
+            Choice::Rock,
+            Choice::Paper,
+            Choice::Rock,
+            Choice::Paper,
+            Choice::Rock,
+        ] {
+            let computer = strategy.choose(&mut rng);
+            strategy.notify(&game.play_round(*human, computer).unwrap());
+        }
+
+        assert_eq!(strategy.choose(&mut rng), Choice::Scissors);
+        assert_eq!(
+            strategy.rationale().unwrap(),
+            "After Rock you usually play Paper, so I'll play Scissors."
+        );
+    }
+
+    #[test]
+    fn frequency_window_forgets_old_choices() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::best_of_rounds(9).unwrap();
+        let mut strategy = FrequencyStrategy::with_window(2);
+        for human in &[
+            Choice::Rock,
+            Choice::Rock,
+            Choice::Rock,
+            Choice::Paper,
+            Choice::Paper,
+        ] {
+            let computer = strategy.choose(&mut rng);
+            strategy.notify(&game.play_round(*human, computer).unwrap());
+        }
+
+        assert_eq!(strategy.choose(&mut rng), Choice::Scissors);
+    }
+
+    #[test]
+    fn frequency_memory_stays_bounded() {
+        let outcome = RoundOutcome::new(Choice::Rock, Choice::Paper, Winner::Computer);
+        let mut counting = FrequencyStrategy::default();
+        let mut windowed = FrequencyStrategy::with_window(3);
+        for _ in 0..1000 {
+            counting.notify(&outcome);
+            windowed.notify(&outcome);
+        }
+
+        assert!(counting.recent.is_empty());
+        assert_eq!(counting.weight(Choice::Rock), 1000.0);
+        assert_eq!(windowed.recent.len(), 3);
+        assert_eq!(windowed.weight(Choice::Rock), 3.0);
+    }
+
+    #[test]
+    fn frequency_decay_follows_a_change_of_tactics() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::new(None).with_win_condition(crate::WinCondition::Endless);
+        let mut decaying = FrequencyStrategy::with_decay(0.5);
+        let mut counting = FrequencyStrategy::default();
+        for (round, human) in [Choice::Rock; 5]
+            .iter()
+            .chain(&[Choice::Scissors; 2])
+            .enumerate()
+        {
+            if round == 5 {
+                assert_eq!(decaying.choose(&mut rng), Choice::Paper);
+            }
+            let outcome = game.play_round(*human, decaying.choose(&mut rng)).unwrap();
+            decaying.notify(&outcome);
+            counting.notify(&outcome);
+        }
+
+        assert_eq!(decaying.choose(&mut rng), Choice::Rock);
+        assert_eq!(counting.choose(&mut rng), Choice::Paper);
+    }
+
+    #[test]
+    fn loses_to_follows_the_rules() {
+        assert_eq!(loses_to(Choice::Rock), Choice::Scissors);
+        assert_eq!(loses_to(Choice::Paper), Choice::Rock);
+        assert_eq!(loses_to(Choice::Scissors), Choice::Paper);
+        for choice in &CHOICES {
+            assert_eq!(
+                choice.play_against(&loses_to(*choice)),
+                crate::PlayResult::Win
+            );
+        }
+    }
+
+    #[test]
+    fn lucky_strategy_always_gives_the_break_at_full_probability() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::best_of_rounds(9).unwrap();
+        let always_scissors = Box::new(WeightedStrategy::new([0, 0, 1]));
+        let mut strategy = LuckyStrategy::new(always_scissors, 1.0);
+        for _ in 0..5 {
+            let computer = strategy.choose(&mut rng);
+            let outcome = game.play_round(Choice::Rock, computer).unwrap();
+            strategy.notify(&outcome);
+            assert_eq!(outcome.winner(), Winner::Human);
+        }
+    }
+
+    #[test]
+    fn registry_names_are_unique_and_parse() {
+        let names: Vec<_> = strategies().iter().map(|info| info.name).collect();
+        assert!(!names.is_empty());
+        for (index, name) in names.iter().enumerate() {
+            assert!(!names[index + 1..].contains(name), "duplicate {}", name);
+        }
+        for name in names.iter().filter(|name| **name != "weighted") {
+            assert!(name.parse::<StrategyConfig>().is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn mirror_strategy_copies_previous_choice() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::default();
+        let mut strategy = MirrorStrategy::default();
+        let computer = strategy.choose(&mut rng);
+        strategy.notify(&game.play_round(Choice::Rock, computer).unwrap());
+
+        assert_eq!(strategy.choose(&mut rng), Choice::Rock);
+    }
+
+    #[test]
+    fn adaptive_strategy_switches_when_two_points_behind() {
+        let mut rng = rand::thread_rng();
+        let mut game = Game::new(Some("7".parse().unwrap()));
+        let mut strategy = AdaptiveStrategy::new(
+            Box::new(AlwaysRockStrategy),
+            Box::new(WeightedStrategy::new([0, 0, 1])),
+            2,
+        );
+        for _ in 0..2 {
+            let computer = strategy.choose(&mut rng);
+            assert_eq!(computer, Choice::Rock);
+            strategy.notify(&game.play_round(Choice::Paper, computer).unwrap());
+        }
+
+        assert!(strategy.is_behind());
+        assert_eq!(strategy.choose(&mut rng), Choice::Scissors);
+        strategy.notify(&game.play_round(Choice::Paper, Choice::Scissors).unwrap());
+        assert!(!strategy.is_behind());
+        assert_eq!(strategy.choose(&mut rng), Choice::Rock);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Choice, Game, GameResult, RoundOutcome, Strategy};
+use rand::RngCore;
+
+/// Iterator returned by [`Game::play_stream`].
+pub struct PlayStream<'a, I> {
+    game: &'a mut Game,
+    human_moves: I,
+    computer: &'a mut dyn Strategy,
+    rng: &'a mut dyn RngCore,
+}
+
+impl Game {
+    /// Plays one round per call to `next`, taking the human's choices from
+    /// `human_moves` and the computer's from `computer`, until the game is
+    /// over or the human moves run out.
+    pub fn play_stream<'a, I>(
+        &'a mut self,
+        human_moves: I,
+        computer: &'a mut dyn Strategy,
+        rng: &'a mut dyn RngCore,
+    ) -> PlayStream<'a, I::IntoIter>
+    where
+        I: IntoIterator<Item = Choice>,
+    {
+        PlayStream {
+            game: self,
+            human_moves: human_moves.into_iter(),
+            computer,
+            rng,
+        }
+    }
+
+    /// Plays the whole [`Game::play_stream`] and returns the [result](Game::result).
+    /// When the human moves run out first the game ends early, and like
+    /// [`Game::resolve_on_time`] the player with more points wins.
+    pub fn run_with<I>(
+        &mut self,
+        human_moves: I,
+        computer: &mut dyn Strategy,
+        rng: &mut dyn RngCore,
+    ) -> GameResult
+    where
+        I: IntoIterator<Item = Choice>,
+    {
+        self.play_stream(human_moves, computer, rng).for_each(drop);
+        self.result()
+    }
+}
+
+impl<I: Iterator<Item = Choice>> Iterator for PlayStream<'_, I> {
+    type Item = RoundOutcome;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.game.is_over() {
+            return None;
+        }
+        let human = self.human_moves.next()?;
+        let computer = self.computer.choose(self.rng);
+        let outcome = self
+            .game
+            .play_round_with_rng(human, computer, self.rng)
+            .ok()?;
+        self.computer.notify(&outcome);
+        Some(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ShuffleBagStrategy, Winner};
+
+    #[derive(Debug)]
+    struct AlwaysScissors;
+
+    impl Strategy for AlwaysScissors {
+        fn choose(&mut self, _rng: &mut dyn RngCore) -> Choice {
+            Choice::Scissors
+        }
+    }
+
+    #[test]
+    fn stream_stops_when_game_is_over() {
+        let mut game = Game::new(None);
+        let moves = vec![Choice::Rock; 10];
+        let outcomes: Vec<_> = game
+            .play_stream(moves, &mut AlwaysScissors, &mut rand::thread_rng())
+            .collect();
+
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes
+            .iter()
+            .all(|outcome| outcome.winner() == Winner::Human));
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn stream_stops_when_moves_run_out() {
+        let mut game = Game::new(None);
+        let mut computer = ShuffleBagStrategy::default();
+        let count = game
+            .play_stream(
+                vec![Choice::Paper, Choice::Rock],
+                &mut computer,
+                &mut rand::thread_rng(),
+            )
+            .count();
+
+        assert_eq!(count, 2);
+        assert_eq!(game.history().len(), 2);
+    }
+
+    #[test]
+    fn run_with_plays_until_clinched() {
+        let mut game = Game::new(None);
+        let result = game.run_with(
+            vec![
+                Choice::Paper,
+                Choice::Scissors,
+                Choice::Rock,
+                Choice::Rock,
+                Choice::Rock,
+            ],
+            &mut AlwaysScissors,
+            &mut rand::thread_rng(),
+        );
+
+        assert_eq!(
+            result,
+            GameResult {
+                winner: Winner::Human,
+                human_points: 3,
+                computer_points: 1,
+                draws: 1,
+                rounds_played: 5,
+            }
+        );
+        assert_eq!(result, game.result());
+    }
+
+    #[test]
+    fn run_with_ends_early_when_moves_run_out() {
+        let mut game = Game::new(None);
+        let result = game.run_with(
+            vec![Choice::Paper, Choice::Paper, Choice::Rock],
+            &mut AlwaysScissors,
+            &mut rand::thread_rng(),
+        );
+
+        assert!(!game.is_over());
+        assert_eq!((game.human_points(), game.computer_points()), (1, 2));
+        assert_eq!(result.winner, Winner::Computer);
+        assert_eq!(result.rounds_played, 3);
+        let empty = Game::new(None).run_with(vec![], &mut AlwaysScissors, &mut rand::thread_rng());
+        assert_eq!(empty.winner, Winner::Draw);
+    }
+}
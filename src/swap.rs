@@ -0,0 +1,98 @@
+use crate::{Choice, Game, RoundOutcome, RpsError};
+
+/// A player changing their choice after seeing the opponent's, which each
+/// player may do once per game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Swap {
+    /// The human replaces their choice with this one.
+    Human(Choice),
+    /// The computer replaces its choice with this one.
+    Computer(Choice),
+}
+
+impl Game {
+    /// Whether the human still has their swap, see [`Game::play_round_with_swap`].
+    pub fn human_can_swap(&self) -> bool {
+        !self.human_swapped
+    }
+
+    /// Whether the computer still has its swap, see [`Game::play_round_with_swap`].
+    pub fn computer_can_swap(&self) -> bool {
+        !self.computer_swapped
+    }
+
+    /// Plays a round like [`Game::play_round`], with one player's choice
+    /// replaced by `swap`, and spends that player's swap.
+    ///
+    /// Fails with [`RpsError::SwapUsed`] when the player already swapped in
+    /// this game; nothing is played or spent then.
+    pub fn play_round_with_swap(
+        &mut self,
+        human: Choice,
+        computer: Choice,
+        swap: Swap,
+    ) -> Result<RoundOutcome, RpsError> {
+        let (human, computer, can_swap) = match swap {
+            Swap::Human(choice) => (choice, computer, self.human_can_swap()),
+            Swap::Computer(choice) => (human, choice, self.computer_can_swap()),
+        };
+        if !can_swap {
+            return Err(RpsError::SwapUsed);
+        }
+        let outcome = self.play_round(human, computer)?;
+        match swap {
+            Swap::Human(_) => self.human_swapped = true,
+            Swap::Computer(_) => self.computer_swapped = true,
+        }
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Winner;
+
+    #[test]
+    fn swap_turns_a_loss_into_a_win() {
+        let mut game = Game::new(None);
+        let outcome = game
+            .play_round_with_swap(Choice::Rock, Choice::Paper, Swap::Human(Choice::Scissors))
+            .unwrap();
+
+        assert_eq!(outcome.human(), Choice::Scissors);
+        assert_eq!(outcome.winner(), Winner::Human);
+        assert_eq!(game.human_points(), 1);
+        assert!(!game.human_can_swap());
+        assert!(game.computer_can_swap());
+    }
+
+    #[test]
+    fn second_swap_is_rejected() {
+        let mut game = Game::new(None);
+        game.play_round_with_swap(Choice::Rock, Choice::Rock, Swap::Computer(Choice::Paper))
+            .unwrap();
+        let second =
+            game.play_round_with_swap(Choice::Rock, Choice::Rock, Swap::Computer(Choice::Paper));
+
+        assert_eq!(second, Err(RpsError::SwapUsed));
+        assert_eq!(game.history().len(), 1);
+
+        game.reset();
+        assert!(game.computer_can_swap());
+    }
+
+    #[test]
+    fn restoring_a_checkpoint_gives_the_swap_back() {
+        let mut game = Game::new(None);
+        let checkpoint = game.checkpoint();
+        game.play_round_with_swap(Choice::Rock, Choice::Rock, Swap::Human(Choice::Paper))
+            .unwrap();
+        game.play_round_with_swap(Choice::Rock, Choice::Rock, Swap::Computer(Choice::Paper))
+            .unwrap();
+
+        game.restore(checkpoint);
+        assert!(game.human_can_swap());
+        assert!(game.computer_can_swap());
+    }
+}
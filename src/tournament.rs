@@ -0,0 +1,252 @@
+use crate::{BestOf, Choice, Game, RoundOutcome, RpsError, WinCondition, Winner};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// One match of a [`Tournament`]. A match without a second player is a bye
+/// and the first player advances without playing.
+#[derive(Debug)]
+pub struct BracketMatch {
+    first: String,
+    second: Option<String>,
+    game: Game,
+}
+
+impl BracketMatch {
+    pub fn first(&self) -> &str {
+        &self.first
+    }
+
+    /// `None` for a bye.
+    pub fn second(&self) -> Option<&str> {
+        self.second.as_deref()
+    }
+
+    /// The match's game, with the first player as the human and the second
+    /// as the computer.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Player advancing from this match, once it is decided.
+    pub fn winner(&self) -> Option<&str> {
+        match (&self.second, self.game.is_over(), self.game.game_winner()) {
+            (None, _, _) => Some(&self.first),
+            (Some(_), true, Winner::Human) => Some(&self.first),
+            (Some(second), true, Winner::Computer) => Some(second),
+            _ => None,
+        }
+    }
+}
+
+/// Single-elimination bracket between named players.
+///
+/// Players are paired in the given order. When their number is not a power
+/// of two, the first players get byes into the second round. Every match is
+/// played until one player wins the majority of the best of, so draws never
+/// decide a match. Once all matches of a round are decided, their winners
+/// are paired into the next round.
+#[derive(Debug)]
+pub struct Tournament {
+    best_of: BestOf,
+    rounds: Vec<Vec<BracketMatch>>,
+}
+
+impl Tournament {
+    /// Creates the bracket, failing with fewer than two players.
+    pub fn new(players: Vec<String>, best_of: BestOf) -> Result<Self, RpsError> {
+        if players.len() < 2 {
+            return Err(RpsError::InvalidTournament(String::from(
+                "at least two players are needed",
+            )));
+        }
+        let byes = players.len().next_power_of_two() - players.len();
+        let mut players = players.into_iter();
+        let mut first_round: Vec<_> = players
+            .by_ref()
+            .take(byes)
+            .map(|player| BracketMatch {
+                first: player,
+                second: None,
+                game: Game::new(Some(best_of)),
+            })
+            .collect();
+        let rest: Vec<_> = players.collect();
+        for pair in rest.chunks(2) {
+            first_round.push(Self::new_match(best_of, pair[0].clone(), pair[1].clone())?);
+        }
+
+        let mut tournament = Tournament {
+            best_of,
+            rounds: Vec::new(),
+        };
+        tournament.rounds.push(first_round);
+        tournament.advance()?;
+        Ok(tournament)
+    }
+
+    fn new_match(best_of: BestOf, first: String, second: String) -> Result<BracketMatch, RpsError> {
+        let game = Game::new(Some(best_of));
+        let threshold = game.winning_threshold();
+        Ok(BracketMatch {
+            first,
+            second: Some(second),
+            game: game.try_with_win_condition(WinCondition::FirstTo(threshold))?,
+        })
+    }
+
+    /// Starts the next round once every match of the current one is decided.
+    fn advance(&mut self) -> Result<(), RpsError> {
+        while self.champion().is_none() {
+            let winners: Option<Vec<String>> = self
+                .current_matches()
+                .iter()
+                .map(|bracket_match| bracket_match.winner().map(String::from))
+                .collect();
+            match winners {
+                Some(winners) => {
+                    let best_of = self.best_of;
+                    let next = winners
+                        .chunks(2)
+                        .map(|pair| Self::new_match(best_of, pair[0].clone(), pair[1].clone()))
+                        .collect::<Result<_, _>>()?;
+                    self.rounds.push(next);
+                }
+                None => return Ok(()),
+            }
+        }
+        Ok(())
+    }
+
+    /// All rounds started so far, the last one being in progress.
+    pub fn rounds(&self) -> &[Vec<BracketMatch>] {
+        &self.rounds
+    }
+
+    /// Matches of the round in progress.
+    pub fn current_matches(&self) -> &[BracketMatch] {
+        self.rounds.last().map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Plays one round of the current round's match at `index`, with the
+    /// choices of its first and second player.
+    pub fn play_round(
+        &mut self,
+        index: usize,
+        first: Choice,
+        second: Choice,
+    ) -> Result<RoundOutcome, RpsError> {
+        let bracket_match = self
+            .rounds
+            .last_mut()
+            .and_then(|round| round.get_mut(index))
+            .ok_or_else(|| RpsError::InvalidTournament(format!("no match {}", index)))?;
+        if bracket_match.second.is_none() {
+            return Err(RpsError::InvalidTournament(format!(
+                "match {} is a bye",
+                index
+            )));
+        }
+        let outcome = bracket_match.game.play_round(first, second)?;
+        self.advance()?;
+        Ok(outcome)
+    }
+
+    /// Winner of the final, once it is decided.
+    pub fn champion(&self) -> Option<&str> {
+        match self.current_matches() {
+            [final_match] if final_match.second.is_some() => final_match.winner(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn players(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    /// Plays the current round, the first player of every match winning.
+    fn first_players_win(tournament: &mut Tournament) {
+        let matches = tournament.current_matches().len();
+        for index in 0..matches {
+            while tournament.current_matches().len() == matches
+                && tournament.current_matches()[index].winner().is_none()
+            {
+                tournament
+                    .play_round(index, Choice::Rock, Choice::Scissors)
+                    .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn four_players_play_to_a_champion() {
+        let best_of = BestOf::new(3).unwrap();
+        let mut tournament =
+            Tournament::new(players(&["Ann", "Bob", "Cid", "Dee"]), best_of).unwrap();
+        assert_eq!(tournament.current_matches().len(), 2);
+
+        tournament
+            .play_round(1, Choice::Rock, Choice::Rock)
+            .unwrap();
+        tournament
+            .play_round(1, Choice::Rock, Choice::Paper)
+            .unwrap();
+        tournament
+            .play_round(1, Choice::Rock, Choice::Paper)
+            .unwrap();
+        assert_eq!(tournament.current_matches()[1].winner(), Some("Dee"));
+        assert_eq!(tournament.rounds().len(), 1);
+
+        first_players_win(&mut tournament);
+        let final_match = &tournament.current_matches()[0];
+        assert_eq!(
+            (final_match.first(), final_match.second()),
+            ("Ann", Some("Dee"))
+        );
+        assert_eq!(tournament.champion(), None);
+
+        first_players_win(&mut tournament);
+        assert_eq!(tournament.champion(), Some("Ann"));
+        assert_eq!(tournament.rounds().len(), 2);
+        assert!(matches!(
+            tournament.play_round(0, Choice::Rock, Choice::Scissors),
+            Err(RpsError::GameOver)
+        ));
+    }
+
+    #[test]
+    fn odd_player_counts_get_byes() {
+        let best_of = BestOf::new(3).unwrap();
+        let mut tournament =
+            Tournament::new(players(&["Ann", "Bob", "Cid", "Dee", "Eve"]), best_of).unwrap();
+        let first_round = tournament.current_matches();
+        assert_eq!(first_round.len(), 4);
+        assert_eq!(first_round[0].second(), None);
+        assert_eq!(first_round[0].winner(), Some("Ann"));
+        assert_eq!(
+            (first_round[3].first(), first_round[3].second()),
+            ("Dee", Some("Eve"))
+        );
+        assert!(tournament
+            .play_round(0, Choice::Rock, Choice::Paper)
+            .is_err());
+
+        first_players_win(&mut tournament);
+        assert_eq!(tournament.current_matches().len(), 2);
+        first_players_win(&mut tournament);
+        first_players_win(&mut tournament);
+        assert_eq!(tournament.champion(), Some("Ann"));
+    }
+
+    #[test]
+    fn needs_two_players() {
+        let best_of = BestOf::new(3).unwrap();
+        assert!(Tournament::new(players(&["Ann"]), best_of).is_err());
+    }
+}
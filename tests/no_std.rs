@@ -0,0 +1,29 @@
+//! Uses only the parts of the crate available without the `std` feature.
+//! Run with `cargo test --no-default-features --test no_std` to check them.
+
+use rock_paper_scissors::{BestOf, Choice, Game, GamePhase, Winner};
+
+#[test]
+fn core_logic_plays_a_game_to_the_end() {
+    assert!(Choice::Rock.beats(&Choice::Scissors));
+    assert!(!Choice::Rock.beats(&Choice::Paper));
+    assert!(!Choice::Rock.beats(&Choice::Rock));
+
+    let mut game = Game::new(Some(BestOf::new(3).unwrap()));
+    assert_eq!(
+        game.round_winner(&Choice::Paper, &Choice::Rock),
+        Winner::Human
+    );
+    game.play_round(Choice::Paper, Choice::Rock).unwrap();
+    game.play_round(Choice::Rock, Choice::Rock).unwrap();
+    assert_eq!(game.phase(), GamePhase::AwaitingMove);
+    game.step_against(Choice::Scissors, Choice::Paper);
+
+    assert_eq!(game.human_points(), 2);
+    assert_eq!(game.draws(), 1);
+    assert_eq!(game.phase(), GamePhase::Finished(Winner::Human));
+    assert_eq!(
+        "rok".parse::<Choice>().unwrap_err().to_string(),
+        "Unknown choice 'rok', did you mean Rock?"
+    );
+}